@@ -0,0 +1,62 @@
+// Cycles per ctfib call for every n, counted with RDTSC. If ctfib
+// really doesn't depend on n they should all come out the same, give or
+// take a few cycles of noise
+use fibonacci_race::constant_time::ctfib;
+use std::hint::black_box;
+
+// Calls per timing, so RDTSC's own overhead gets spread thin
+const CALLS: u64 = 100;
+// Timings per n, keeping the fastest, which is the one that got
+// interrupted the least
+const SAMPLES: usize = 200;
+// How far apart the slowest and fastest n can be
+const MAX_SPREAD: f64 = 10.0;
+
+#[cfg(target_arch = "x86_64")]
+fn cycles_per_call(n: u128) -> f64 {
+    use std::arch::x86_64::_rdtsc;
+
+    // safe, it only reads the timestamp counter
+    let start = unsafe { _rdtsc() };
+    for _ in 0..CALLS {
+        black_box(ctfib(black_box(n)));
+    }
+    let end = unsafe { _rdtsc() };
+    (end - start) as f64 / CALLS as f64
+}
+
+#[cfg(target_arch = "x86_64")]
+fn main() {
+    // every sample goes through all the ns in turn, so if the clock
+    // speed drifts partway through it hits each n the same
+    let mut cycles = vec![f64::INFINITY; 187];
+    for _ in 0..SAMPLES {
+        for (n, fastest) in cycles.iter_mut().enumerate() {
+            *fastest = fastest.min(cycles_per_call(n as u128));
+        }
+    }
+    let fastest = cycles.iter().cloned().fold(f64::INFINITY, f64::min);
+    let slowest = cycles.iter().cloned().fold(0.0, f64::max);
+    println!(
+        "ctfib over n = 0..=186: {:.1} to {:.1} cycles a call, a spread of {:.1}",
+        fastest,
+        slowest,
+        slowest - fastest
+    );
+    if slowest - fastest < MAX_SPREAD {
+        println!(
+            "under {} cycles, so n doesn't show in the timing",
+            MAX_SPREAD
+        );
+    } else {
+        println!(
+            "WARNING: {:.1} cycles or more apart, something depends on n (or the machine is noisy)",
+            MAX_SPREAD
+        );
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn main() {
+    println!("ct_bench needs RDTSC, which only x86_64 has");
+}
@@ -0,0 +1,159 @@
+use bumpalo::Bump;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fibonacci_race::arena::FibArena;
+use fibonacci_race::bigint::{bigint_fib, karatsuba_mul};
+use fibonacci_race::fib_hash_map::FibHashMap;
+use fibonacci_race::ladder::{binary_pow, fib_ladder_pow};
+use fibonacci_race::search::fibonacci_search;
+use fibonacci_race::skip_list::FibSkipList;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeSet, HashMap};
+
+fn search_benchmark(c: &mut Criterion) {
+    let haystack: Vec<u64> = (0..10_000).map(|i| i * 3).collect();
+    let needles: Vec<u64> = (0..1_000).map(|i| i * 29).collect();
+
+    let mut group = c.benchmark_group("search");
+    group.bench_function("fibonacci_search", |b| {
+        b.iter(|| {
+            for needle in &needles {
+                black_box(fibonacci_search(&haystack, needle));
+            }
+        })
+    });
+    group.bench_function("binary_search", |b| {
+        b.iter(|| {
+            for needle in &needles {
+                black_box(haystack.binary_search(needle).ok());
+            }
+        })
+    });
+    group.finish();
+}
+
+fn hash_map_benchmark(c: &mut Criterion) {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(186);
+    let keys: Vec<u64> = (0..10_000).map(|_| rng.gen()).collect();
+
+    let mut group = c.benchmark_group("hash_map_insert");
+    group.bench_function("FibHashMap", |b| {
+        b.iter(|| {
+            let mut map = FibHashMap::new();
+            for &key in &keys {
+                map.insert(key, key);
+            }
+            black_box(map.len())
+        })
+    });
+    group.bench_function("HashMap", |b| {
+        b.iter(|| {
+            let mut map = HashMap::new();
+            for &key in &keys {
+                map.insert(key, key);
+            }
+            black_box(map.len())
+        })
+    });
+    group.finish();
+}
+
+fn skip_list_benchmark(c: &mut Criterion) {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(186);
+    let values: Vec<u64> = (0..10_000).map(|_| rng.gen()).collect();
+
+    let mut group = c.benchmark_group("sorted_set_insert");
+    group.bench_function("FibSkipList", |b| {
+        b.iter(|| {
+            let mut list = FibSkipList::new();
+            for &value in &values {
+                list.insert(value);
+            }
+            black_box(list.len())
+        })
+    });
+    group.bench_function("BTreeSet", |b| {
+        b.iter(|| {
+            let mut set = BTreeSet::new();
+            for &value in &values {
+                set.insert(value);
+            }
+            black_box(set.len())
+        })
+    });
+    group.finish();
+}
+
+fn arena_benchmark(c: &mut Criterion) {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(186);
+    let sizes: Vec<usize> = (0..10_000).map(|_| rng.gen_range(1..200)).collect();
+
+    // both get reused across iterations, so it's allocation speed being
+    // measured and not asking the system for memory
+    let mut group = c.benchmark_group("arena_alloc");
+    let mut arena = FibArena::new();
+    group.bench_function("FibArena", |b| {
+        b.iter(|| {
+            for &size in &sizes {
+                black_box(arena.alloc(size));
+            }
+            arena.reset();
+        })
+    });
+    let mut bump = Bump::new();
+    group.bench_function("bumpalo", |b| {
+        b.iter(|| {
+            for &size in &sizes {
+                black_box(bump.alloc_slice_fill_copy(size, 0u8));
+            }
+            bump.reset();
+        })
+    });
+    group.finish();
+}
+
+fn modpow_benchmark(c: &mut Criterion) {
+    let modulus = (1 << 61) - 1;
+    let exps: Vec<u128> = (0..1_000).map(|i| i * 1_000_003).collect();
+
+    let mut group = c.benchmark_group("modpow");
+    group.bench_function("fib_ladder_pow", |b| {
+        b.iter(|| {
+            for &exp in &exps {
+                black_box(fib_ladder_pow(3, exp, modulus));
+            }
+        })
+    });
+    group.bench_function("binary_pow", |b| {
+        b.iter(|| {
+            for &exp in &exps {
+                black_box(binary_pow(3, exp, modulus));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bignum_multiply_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bignum_multiply");
+    for &n in &[100, 1_000, 10_000] {
+        let fib = bigint_fib(n);
+        group.bench_with_input(BenchmarkId::new("num-bigint", n), &fib, |b, fib| {
+            b.iter(|| black_box(fib * fib))
+        });
+        group.bench_with_input(BenchmarkId::new("karatsuba_mul", n), &fib, |b, fib| {
+            b.iter(|| black_box(karatsuba_mul(fib, fib)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    search_benchmark,
+    hash_map_benchmark,
+    skip_list_benchmark,
+    arena_benchmark,
+    modpow_benchmark,
+    bignum_multiply_benchmark
+);
+criterion_main!(benches);
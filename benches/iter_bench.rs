@@ -0,0 +1,23 @@
+// Nightly only (#[bench] is still unstable), so Cargo.toml only builds
+// this with the nightly-bench feature turned on
+#![feature(test)]
+
+extern crate test;
+
+use fibonacci_race::iter::{fibonacci_iter, fibonacci_iter_from, FibIter};
+use test::{black_box, Bencher};
+
+#[bench]
+fn full_scan(b: &mut Bencher) {
+    b.iter(|| black_box(FibIter::new().take(186).count()));
+}
+
+#[bench]
+fn scan_from_100(b: &mut Bencher) {
+    b.iter(|| black_box(fibonacci_iter_from(black_box(100)).take(86).count()));
+}
+
+#[bench]
+fn filter_even(b: &mut Bencher) {
+    b.iter(|| black_box(fibonacci_iter().filter(|x| x.is_multiple_of(2)).count()));
+}
@@ -0,0 +1,32 @@
+// Nightly only, for both #[bench] and std::simd, so Cargo.toml only
+// builds this with the simd feature turned on
+#![feature(test)]
+
+extern crate test;
+
+use fibonacci_race::better_dynamic_fib;
+use fibonacci_race::simd::simd_batch_fib;
+use test::{black_box, Bencher};
+
+fn inputs() -> Vec<u128> {
+    (0..100).map(|i| 50 + i % 44).collect()
+}
+
+#[bench]
+fn scalar_batch(b: &mut Bencher) {
+    let inputs = inputs();
+    b.iter(|| {
+        black_box(
+            black_box(&inputs)
+                .iter()
+                .map(|&n| better_dynamic_fib(n))
+                .collect::<Vec<_>>(),
+        )
+    });
+}
+
+#[bench]
+fn simd_batch(b: &mut Bencher) {
+    let inputs = inputs();
+    b.iter(|| black_box(simd_batch_fib(black_box(&inputs))));
+}
@@ -0,0 +1,17 @@
+// better_dynamic_fib as a --plugin. Build it with
+//   cargo build --example plugin_example
+// and then race it with
+//   fibonacci_race --plugin target/debug/examples/libplugin_example.so 50
+// (.dylib on macOS, plugin_example.dll on Windows)
+use fibonacci_race::better_dynamic_fib;
+use std::os::raw::c_char;
+
+#[no_mangle]
+pub extern "C" fn compute(fib_num: u128) -> u128 {
+    better_dynamic_fib(fib_num)
+}
+
+#[no_mangle]
+pub extern "C" fn name() -> *const c_char {
+    b"better_dynamic_fib, loaded as a plugin\0".as_ptr() as *const c_char
+}
@@ -0,0 +1,55 @@
+use std::io;
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_pin_to_core() {
+    let cores = available_cores();
+    assert!(!cores.is_empty());
+    let core = *cores.last().unwrap();
+    std::thread::spawn(move || {
+        pin_to_core(core).unwrap();
+        assert_eq!(available_cores(), [core]);
+    })
+    .join()
+    .unwrap();
+}
+
+// The cores this process is allowed to run on, which is empty when
+// there's no way to pin threads to cores at all
+#[cfg(target_os = "linux")]
+pub fn available_cores() -> Vec<usize> {
+    // cpu_set_t is a plain bitmask, so all zeroes is a valid empty set
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    let size = std::mem::size_of::<libc::cpu_set_t>();
+    if unsafe { libc::sched_getaffinity(0, size, &mut set) } != 0 {
+        return Vec::new();
+    }
+    (0..libc::CPU_SETSIZE as usize)
+        .filter(|&core| unsafe { libc::CPU_ISSET(core, &set) })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn available_cores() -> Vec<usize> {
+    Vec::new()
+}
+
+// Keep the calling thread (and only it) on just this one core
+#[cfg(target_os = "linux")]
+pub fn pin_to_core(core: usize) -> io::Result<()> {
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    unsafe { libc::CPU_SET(core, &mut set) };
+    let size = std::mem::size_of::<libc::cpu_set_t>();
+    if unsafe { libc::sched_setaffinity(0, size, &set) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn pin_to_core(_core: usize) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Other,
+        "pinning to a core only works on Linux",
+    ))
+}
@@ -0,0 +1,220 @@
+use crate::bigint::adaptive_fib;
+use crate::{
+    backtrace_fib, backtrace_memo_fib, better_dynamic_fib, cached_fib, dynamic_fib, lookup_fib,
+    tailrec_fib,
+};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_algorithm_kinds() {
+    for &kind in &AlgorithmKind::ALL {
+        assert_eq!(AlgorithmKind::from_name(kind.name()), Some(kind));
+        assert_eq!(kind.compute(20), 6765);
+    }
+    assert_eq!(AlgorithmKind::from_name("bogus"), None);
+}
+
+#[test]
+fn test_benchmark_all() {
+    let results = benchmark_all(20);
+    let kinds: Vec<AlgorithmKind> = results.iter().map(|result| result.kind).collect();
+    assert_eq!(kinds, AlgorithmKind::ALL);
+    for result in &results {
+        assert_eq!((result.n, result.value), (20, 6765));
+    }
+}
+
+#[test]
+fn test_fastest() {
+    let result = |kind, nanos| AlgorithmResult {
+        kind,
+        n: 50,
+        elapsed: Duration::from_nanos(nanos),
+        value: 12_586_269_025,
+    };
+    let results = [
+        result(AlgorithmKind::Dynamic, 900),
+        result(AlgorithmKind::Lookup, 40),
+        result(AlgorithmKind::Cached, 60),
+    ];
+    assert_eq!(fastest(&results), Some(results[1]));
+    assert_eq!(fastest(&[]), None);
+
+    // the replay is only ever the winner, and it still gets F(50) right
+    let race: Vec<AlgorithmResult> = AlgorithmKind::ALL[1..]
+        .iter()
+        .map(|&kind| benchmark(kind, 50))
+        .collect();
+    let winner = fastest(&race).unwrap().kind;
+    let replay = replay(winner, 50, 20);
+    assert_eq!(replay.len(), 20);
+    assert!(replay
+        .iter()
+        .all(|result| (result.kind, result.value) == (winner, 12_586_269_025)));
+    assert_eq!(fastest(&replay).map(|result| result.kind), Some(winner));
+}
+
+#[test]
+fn test_mean_and_stddev() {
+    let results: Vec<AlgorithmResult> = [2, 4, 4, 4, 5, 5, 7, 9]
+        .iter()
+        .map(|&nanos| AlgorithmResult {
+            kind: AlgorithmKind::Lookup,
+            n: 20,
+            elapsed: Duration::from_nanos(nanos),
+            value: 6765,
+        })
+        .collect();
+    let (mean, stddev) = mean_and_stddev(&results);
+    assert_eq!(mean, 5.0);
+    assert!((stddev - (32.0f64 / 7.0).sqrt()).abs() < 1e-12);
+    assert_eq!(mean_and_stddev(&results[..1]), (2.0, 0.0));
+    assert_eq!(mean_and_stddev(&[]), (0.0, 0.0));
+}
+
+// Every way we know to solve it, in the order they race
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlgorithmKind {
+    Backtrace,
+    BacktraceMemo,
+    Dynamic,
+    BetterDynamic,
+    Tailrec,
+    Cached,
+    Lookup,
+    Adaptive,
+}
+
+impl AlgorithmKind {
+    pub const ALL: [AlgorithmKind; 8] = [
+        AlgorithmKind::Backtrace,
+        AlgorithmKind::BacktraceMemo,
+        AlgorithmKind::Dynamic,
+        AlgorithmKind::BetterDynamic,
+        AlgorithmKind::Tailrec,
+        AlgorithmKind::Cached,
+        AlgorithmKind::Lookup,
+        AlgorithmKind::Adaptive,
+    ];
+
+    // What to call it on the command line
+    pub fn name(self) -> &'static str {
+        match self {
+            AlgorithmKind::Backtrace => "backtrace",
+            AlgorithmKind::BacktraceMemo => "backtrace_memo",
+            AlgorithmKind::Dynamic => "dynamic",
+            AlgorithmKind::BetterDynamic => "better_dynamic",
+            AlgorithmKind::Tailrec => "tailrec",
+            AlgorithmKind::Cached => "cached",
+            AlgorithmKind::Lookup => "lookup",
+            AlgorithmKind::Adaptive => "adaptive",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            AlgorithmKind::Backtrace => "simple backtracing/recursion",
+            AlgorithmKind::BacktraceMemo => "backtracing/recursion with memoization",
+            AlgorithmKind::Dynamic => "dynamic programming with memoization via HashMap",
+            AlgorithmKind::BetterDynamic => "dynamic programming with memoization via tuple",
+            AlgorithmKind::Tailrec => "tail recursion with accumulators",
+            AlgorithmKind::Cached => "cached function",
+            AlgorithmKind::Lookup => "lookup table built at compile time",
+            AlgorithmKind::Adaptive => "adaptive u128/BigUint",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<AlgorithmKind> {
+        AlgorithmKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.name() == name)
+    }
+
+    pub fn names() -> Vec<&'static str> {
+        AlgorithmKind::ALL.iter().map(|kind| kind.name()).collect()
+    }
+
+    // The memo version starts with a fresh HashMap every time, otherwise
+    // it would just be looking up last round's answer
+    pub fn compute(self, fib_num: u128) -> u128 {
+        match self {
+            AlgorithmKind::Backtrace => backtrace_fib(fib_num),
+            AlgorithmKind::BacktraceMemo => backtrace_memo_fib(&mut HashMap::new(), fib_num),
+            AlgorithmKind::Dynamic => dynamic_fib(fib_num),
+            AlgorithmKind::BetterDynamic => better_dynamic_fib(fib_num),
+            AlgorithmKind::Tailrec => tailrec_fib(fib_num),
+            AlgorithmKind::Cached => cached_fib(fib_num),
+            AlgorithmKind::Lookup => lookup_fib(fib_num),
+            // never past F(186) in a race, so it's always the u128 side
+            AlgorithmKind::Adaptive => adaptive_fib(fib_num as u64)
+                .to_u128()
+                .expect("F(n) doesn't fit in a u128"),
+        }
+    }
+}
+
+// One algorithm's go at F(n)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlgorithmResult {
+    pub kind: AlgorithmKind,
+    pub n: u128,
+    pub elapsed: Duration,
+    pub value: u128,
+}
+
+// Time a single compute, without printing anything
+pub fn benchmark(kind: AlgorithmKind, n: u128) -> AlgorithmResult {
+    let now = Instant::now();
+    let value = kind.compute(n);
+    let elapsed = now.elapsed();
+    AlgorithmResult {
+        kind,
+        n,
+        elapsed,
+        value,
+    }
+}
+
+// Every algorithm once, in race order, for anything that wants the
+// numbers instead of the race printout
+pub fn benchmark_all(n: u128) -> Vec<AlgorithmResult> {
+    AlgorithmKind::ALL
+        .iter()
+        .map(|&kind| benchmark(kind, n))
+        .collect()
+}
+
+// The one that took the least time, the first of them if there's a tie
+pub fn fastest(results: &[AlgorithmResult]) -> Option<AlgorithmResult> {
+    results.iter().copied().min_by_key(|result| result.elapsed)
+}
+
+// kind over and over on the same n, for a better idea of how long it
+// really takes than one timing can give
+pub fn replay(kind: AlgorithmKind, n: u128, times: usize) -> Vec<AlgorithmResult> {
+    (0..times).map(|_| benchmark(kind, n)).collect()
+}
+
+// Mean and (sample) standard deviation of the timings, in nanoseconds.
+// A single timing has nothing to deviate from, so that's 0
+pub fn mean_and_stddev(results: &[AlgorithmResult]) -> (f64, f64) {
+    if results.is_empty() {
+        return (0.0, 0.0);
+    }
+    let nanos: Vec<f64> = results
+        .iter()
+        .map(|result| result.elapsed.as_nanos() as f64)
+        .collect();
+    let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+    if nanos.len() == 1 {
+        return (mean, 0.0);
+    }
+    let variance = nanos
+        .iter()
+        .map(|nanos| (nanos - mean).powi(2))
+        .sum::<f64>()
+        / (nanos.len() - 1) as f64;
+    (mean, variance.sqrt())
+}
@@ -0,0 +1,106 @@
+use crate::{lookup_fib, MAX_FIB_INDEX_U128};
+use std::f64::consts::PI;
+use std::fs;
+use std::io;
+
+#[test]
+fn test_fib_audio_samples() {
+    let samples = fib_audio_samples(5, 8000, 250);
+    assert_eq!(samples.len(), 5 * 2000);
+    assert!(samples.iter().all(|sample| (-1.0..=1.0).contains(sample)));
+    // each tone starts at the start of a sine wave
+    assert_eq!(samples[0], 0.0);
+    assert_eq!(samples[2000], 0.0);
+    // 1 Hz for a quarter second peaks a quarter of the way in
+    assert!((samples[1999] - 1.0).abs() < 1e-6);
+    assert!(fib_audio_samples(0, 8000, 250).is_empty());
+}
+
+#[test]
+fn test_wav_header() {
+    let samples = [0.0, 1.0, -1.0, 0.5];
+    let wav = wav_bytes(&samples, 44_100);
+    let u16_at = |at: usize| u16::from_le_bytes([wav[at], wav[at + 1]]);
+    let u32_at = |at: usize| u32::from_le_bytes([wav[at], wav[at + 1], wav[at + 2], wav[at + 3]]);
+
+    assert_eq!(wav.len(), 44 + 8);
+    assert_eq!(&wav[0..4], b"RIFF");
+    assert_eq!(u32_at(4), 36 + 8);
+    assert_eq!(&wav[8..12], b"WAVE");
+    assert_eq!(&wav[12..16], b"fmt ");
+    assert_eq!(u32_at(16), 16);
+    // PCM, mono
+    assert_eq!((u16_at(20), u16_at(22)), (1, 1));
+    assert_eq!(u32_at(24), 44_100);
+    assert_eq!(u32_at(28), 44_100 * 2);
+    // 2 bytes a sample, 16 bits
+    assert_eq!((u16_at(32), u16_at(34)), (2, 16));
+    assert_eq!(&wav[36..40], b"data");
+    assert_eq!(u32_at(40), 8);
+    let data: Vec<i16> = (44..wav.len())
+        .step_by(2)
+        .map(|at| u16_at(at) as i16)
+        .collect();
+    assert_eq!(data, [0, i16::MAX, -i16::MAX, 16384]);
+
+    let path = std::env::temp_dir().join("fibonacci_race_test.wav");
+    let path = path.to_str().unwrap();
+    write_wav(&samples, 44_100, path).unwrap();
+    assert_eq!(fs::read(path).unwrap(), wav);
+    fs::remove_file(path).unwrap();
+}
+
+// F(1) Hz, then F(2) Hz, and so on up to F(n) Hz, each a plain sine wave
+// for duration_ms. Anything past half the sample rate (F(24) = 46368 Hz
+// is already past what 44.1kHz can hold) aliases down into something
+// lower, and only F(186) fits, so that's where n stops
+pub fn fib_audio_samples(n: u8, sample_rate: u32, duration_ms: u32) -> Vec<f32> {
+    assert!(
+        n as u128 <= MAX_FIB_INDEX_U128,
+        "F({}) doesn't fit in a u128",
+        n
+    );
+    let per_tone = sample_rate as u64 * duration_ms as u64 / 1000;
+    let mut samples = Vec::new();
+    for k in 1..=n as u128 {
+        let freq = lookup_fib(k) as f64;
+        samples.extend((0..per_tone).map(|i| {
+            let t = i as f64 / sample_rate as f64;
+            (2.0 * PI * freq * t).sin() as f32
+        }));
+    }
+    samples
+}
+
+// A minimal WAV file: the RIFF header, a fmt chunk for 16 bit mono PCM,
+// and the samples scaled up to i16 in a data chunk
+pub fn wav_bytes(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let data_size = samples.len() as u32 * 2;
+    let mut wav = Vec::with_capacity(44 + data_size as usize);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_size).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    // format 1 is PCM, then 1 channel
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    // bytes a second, bytes a sample, bits a sample
+    wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_size.to_le_bytes());
+    for &sample in samples {
+        let sample = (sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16;
+        wav.extend_from_slice(&sample.to_le_bytes());
+    }
+    wav
+}
+
+pub fn write_wav(samples: &[f32], sample_rate: u32, path: &str) -> io::Result<()> {
+    fs::write(path, wav_bytes(samples, sample_rate))
+}
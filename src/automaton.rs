@@ -0,0 +1,77 @@
+#[test]
+fn test_pisano_periods() {
+    let known = [(1, 1), (2, 3), (3, 8), (4, 6), (5, 20), (10, 60)];
+    for &(modulus, period) in &known {
+        assert_eq!(FibAutomaton::new(modulus).period(), period);
+    }
+
+    let residues: Vec<u128> = FibAutomaton::new(10).take(12).collect();
+    assert_eq!(residues, [0, 1, 1, 2, 3, 5, 8, 3, 1, 4, 5, 9]);
+
+    // 2·5^k is where the period hits 6M
+    assert_eq!(
+        FibAutomaton::new(2 * 5u128.pow(9)).period(),
+        6 * 2 * 5u64.pow(9)
+    );
+}
+
+// The Pisano period of M can be as long as 6M, and period() walks it a
+// step at a time, so this keeps --automaton to well under a second
+pub const MAX_AUTOMATON_MODULUS: u128 = 10_000_000;
+
+// Walks the Fibonacci sequence mod some number, the state is just
+// the last two values (already reduced) so there are only modulus²
+// possible states and it has to loop eventually (the Pisano period)
+pub struct FibAutomaton {
+    state: (u128, u128),
+    modulus: u128,
+}
+
+impl FibAutomaton {
+    pub fn new(modulus: u128) -> FibAutomaton {
+        assert!(modulus > 0, "can't take Fibonacci numbers mod 0");
+        FibAutomaton {
+            state: (0, 1 % modulus),
+            modulus,
+        }
+    }
+
+    // (a + b) % modulus without overflowing when modulus is near u128::MAX
+    fn step(&mut self) {
+        let (a, b) = self.state;
+        let sum = if b >= self.modulus - a {
+            b - (self.modulus - a)
+        } else {
+            a + b
+        };
+        self.state = (b, sum);
+    }
+
+    // Floyd's tortoise and hare would find the cycle from any starting
+    // point, but Pisano sequences are purely periodic (they always come
+    // back around to (0, 1)) so the tortoise can just sit on the start
+    // while the hare runs. Restarts the automaton, and since it stops at
+    // the start state it is left ready to iterate from F(0) again
+    pub fn period(&mut self) -> u64 {
+        let start = (0, 1 % self.modulus);
+        self.state = start;
+        let mut period = 0;
+        loop {
+            self.step();
+            period += 1;
+            if self.state == start {
+                return period;
+            }
+        }
+    }
+}
+
+impl Iterator for FibAutomaton {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        let current = self.state.0;
+        self.step();
+        Some(current)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::iter::FibIter;
+use std::time::Duration;
+
+#[test]
+fn test_fib_backoff() {
+    let mut backoff = FibBackoff::new(Duration::from_millis(1), Duration::from_millis(10));
+    let delays: Vec<u128> = (0..8).map(|_| backoff.next_delay().as_millis()).collect();
+    assert_eq!(delays, [1, 1, 2, 3, 5, 8, 10, 10]);
+    backoff.reset();
+    assert_eq!(backoff.next_delay(), Duration::from_millis(1));
+
+    // keeps handing out the max even once the Fibonacci numbers run out
+    let mut backoff = FibBackoff::default();
+    for _ in 0..200 {
+        assert!(backoff.next_delay() <= Duration::from_secs(60));
+    }
+    assert_eq!(backoff.next_delay(), Duration::from_secs(60));
+}
+
+// Waits of 1, 1, 2, 3, 5, 8... times some base delay between retries.
+// That grows by φ each time instead of doubling, so it backs off a bit
+// more gently than the usual exponential backoff
+pub struct FibBackoff {
+    current: Duration,
+    max: Duration,
+    base: Duration,
+    iter: FibIter,
+}
+
+impl FibBackoff {
+    pub fn new(base: Duration, max: Duration) -> FibBackoff {
+        let mut iter = FibIter::new();
+        iter.next(); // F(0) would mean not waiting at all
+        FibBackoff {
+            current: Duration::from_secs(0),
+            max,
+            base,
+            iter,
+        }
+    }
+
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self
+            .iter
+            .next()
+            .and_then(|fib| self.base.as_nanos().checked_mul(fib))
+            .filter(|&nanos| nanos < self.max.as_nanos())
+            .map(|nanos| Duration::from_nanos(nanos as u64))
+            .unwrap_or(self.max);
+        self.current = delay;
+        delay
+    }
+
+    // The last delay handed out
+    pub fn current(&self) -> Duration {
+        self.current
+    }
+
+    // Start over from the shortest delay, like after a retry works
+    pub fn reset(&mut self) {
+        *self = FibBackoff::new(self.base, self.max);
+    }
+}
+
+// 1ms to start, but never more than a minute
+impl Default for FibBackoff {
+    fn default() -> Self {
+        FibBackoff::new(Duration::from_millis(1), Duration::from_secs(60))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::FIB_TABLE;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_buffers() {
+    let fibs = fibonacci_buffer(400);
+    assert_eq!(&fibs[..6], &[0, 1, 1, 2, 3, 5]);
+    // round again after F(186)
+    assert_eq!(fibs[186], crate::lookup_fib(186));
+    assert_eq!(&fibs[187..190], &[0, 1, 1]);
+    assert_eq!(sequential_buffer(4), [0, 1, 2, 3]);
+}
+
+#[test]
+fn test_measure_bandwidth() {
+    let mut data = fibonacci_buffer(10_000);
+    let bandwidth = measure_bandwidth(&mut data, 3);
+    assert!(bandwidth.read > 0.0 && bandwidth.read.is_finite());
+    assert!(bandwidth.write > 0.0 && bandwidth.write.is_finite());
+    // the last pass wrote its own number everywhere
+    assert!(data.iter().all(|&value| value == 2));
+}
+
+// The most --bandwidth will allocate, 1GB worth of u128s
+pub const MAX_BANDWIDTH_LEN: usize = 1 << 26;
+
+// GB per second each way
+pub struct Bandwidth {
+    pub read: f64,
+    pub write: f64,
+}
+
+// len Fibonacci numbers in a row, starting over from F(0) every time
+// it gets past F(186)
+pub fn fibonacci_buffer(len: usize) -> Vec<u128> {
+    FIB_TABLE.iter().copied().cycle().take(len).collect()
+}
+
+// 0, 1, 2, ... to measure the Fibonacci buffer against
+pub fn sequential_buffer(len: usize) -> Vec<u128> {
+    (0..len as u128).collect()
+}
+
+fn gb_per_s(bytes: usize, elapsed: Duration) -> f64 {
+    bytes as f64 / elapsed.max(Duration::from_nanos(1)).as_secs_f64() / 1e9
+}
+
+// Sum everything reps times over, then overwrite everything reps times
+// over (with the pass number, so each pass writes something new).
+// black_box after each pass keeps the compiler from skipping passes it
+// can tell nobody looks at. Both walk the memory front to back, so what
+// the values are doesn't matter to the hardware at all, only how many
+// bytes there are
+pub fn measure_bandwidth(data: &mut [u128], reps: usize) -> Bandwidth {
+    let bytes = std::mem::size_of_val(data) * reps;
+
+    let now = Instant::now();
+    for _ in 0..reps {
+        let sum = data
+            .iter()
+            .fold(0u128, |sum, &value| sum.wrapping_add(value));
+        black_box(sum);
+    }
+    let read = gb_per_s(bytes, now.elapsed());
+
+    let now = Instant::now();
+    for pass in 0..reps {
+        data.fill(pass as u128);
+        black_box(&mut *data);
+    }
+    let write = gb_per_s(bytes, now.elapsed());
+
+    Bandwidth { read, write }
+}
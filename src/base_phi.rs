@@ -0,0 +1,149 @@
+use num_bigint::BigInt;
+use std::convert::TryFrom;
+use std::fmt;
+
+#[test]
+fn test_to_base_phi() {
+    assert_eq!(to_base_phi(0), "0.0");
+    assert_eq!(to_base_phi(1), "1.0");
+    assert_eq!(to_base_phi(2), "10.01");
+    assert_eq!(to_base_phi(3), "100.01");
+    assert_eq!(to_base_phi(4), "101.01");
+    assert_eq!(to_base_phi(5), "1000.1001");
+    assert_eq!(to_base_phi(10), "10100.0101");
+    for n in (0..=1000).chain([u64::MAX as u128, u128::MAX]) {
+        let phi = to_base_phi(n);
+        assert!(!phi.contains("11"), "{} has two 1s in a row", phi);
+        assert_eq!(from_base_phi(&phi), Ok(n));
+    }
+}
+
+#[test]
+fn test_from_base_phi() {
+    // two 1s in a row is still a number, just not the usual way to write it
+    assert_eq!(from_base_phi("11"), from_base_phi("100"));
+    assert_eq!(from_base_phi("10.01"), Ok(2));
+    assert_eq!(from_base_phi("0010"), from_base_phi("10.0"));
+    assert_eq!(from_base_phi(""), Err(ParseError::Empty));
+    assert_eq!(from_base_phi("."), Err(ParseError::Empty));
+    assert_eq!(from_base_phi("102"), Err(ParseError::InvalidDigit('2')));
+    assert_eq!(from_base_phi("1.0.1"), Err(ParseError::InvalidDigit('.')));
+    // φ itself
+    assert_eq!(from_base_phi("10"), Err(ParseError::NotAnInteger));
+    // φ^k + φ^-k for even k is the Lucas number L(k), and L(200) is
+    // way past u128::MAX
+    let lucas_200 = format!("1{}.{}1", "0".repeat(200), "0".repeat(199));
+    assert_eq!(from_base_phi(&lucas_200), Err(ParseError::TooBig));
+    assert_eq!(
+        from_base_phi(&format!("1{}.{}1", "0".repeat(4), "0".repeat(3))),
+        Ok(7)
+    );
+}
+
+// Why a string isn't a base φ integer
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidDigit(char),
+    NotAnInteger,
+    TooBig,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "there aren't any digits"),
+            ParseError::InvalidDigit(c) => write!(f, "{:?} isn't a base φ digit", c),
+            ParseError::NotAnInteger => write!(f, "that isn't a whole number"),
+            ParseError::TooBig => write!(f, "that doesn't fit in a u128"),
+        }
+    }
+}
+
+// Where φ^0 sits in the digits. log_φ(2^128) is under 185, so the
+// biggest u128 never needs a digit further out than that either way
+const ONES_PLACE: usize = 200;
+
+// Carry until every digit is 0 or 1 with no 1s next to each other,
+// using 2φ^k = φ^(k+1) + φ^(k-2) and φ^k + φ^(k+1) = φ^(k+2)
+fn normalize(digits: &mut [u8]) {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 2..digits.len() - 2 {
+            if digits[i] >= 2 {
+                digits[i] -= 2;
+                digits[i + 1] += 1;
+                digits[i - 2] += 1;
+                changed = true;
+            } else if digits[i] >= 1 && digits[i + 1] >= 1 {
+                digits[i] -= 1;
+                digits[i + 1] -= 1;
+                digits[i + 2] += 1;
+                changed = true;
+            }
+        }
+    }
+}
+
+// n in base φ (Bergman's "golden ratio base"): every digit is 0 or 1,
+// no two 1s are next to each other, and the places are powers of φ, so
+// whole numbers past 1 end up needing a fractional part too (2 is
+// φ + φ^-2, or 10.01). Built like binary is read, doubling and adding
+// the next bit each time, with the carrying done in base φ
+pub fn to_base_phi(n: u128) -> String {
+    let mut digits = [0u8; 2 * ONES_PLACE];
+    for bit in (0..128).rev() {
+        for digit in digits.iter_mut() {
+            *digit *= 2;
+        }
+        digits[ONES_PLACE] += (n >> bit & 1) as u8;
+        normalize(&mut digits);
+    }
+
+    let highest = (ONES_PLACE..digits.len())
+        .rev()
+        .find(|&place| digits[place] == 1)
+        .unwrap_or(ONES_PLACE);
+    let lowest = (0..ONES_PLACE)
+        .find(|&place| digits[place] == 1)
+        .unwrap_or(ONES_PLACE - 1);
+    let digit = |place: usize| (b'0' + digits[place]) as char;
+    let whole: String = (ONES_PLACE..=highest).rev().map(digit).collect();
+    let fraction: String = (lowest..ONES_PLACE).rev().map(digit).collect();
+    format!("{}.{}", whole, fraction)
+}
+
+// Back from base φ, keeping everything as a + bφ (with a and b whole
+// numbers) so there's no rounding: φ(a + bφ) = b + (a + b)φ because
+// φ^2 = φ + 1, and (a + bφ)/φ = (b - a) + aφ. Any 0s and 1s are
+// allowed, not just the usual way of writing it, but the result has to
+// work out to a whole number that fits in a u128
+pub fn from_base_phi(s: &str) -> Result<u128, ParseError> {
+    let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let digit = |c: char| match c {
+        '0' => Ok(0u8),
+        '1' => Ok(1),
+        _ => Err(ParseError::InvalidDigit(c)),
+    };
+
+    let mut value = (BigInt::from(0u8), BigInt::from(0u8));
+    for c in whole.chars() {
+        let (a, b) = value;
+        value = (b.clone() + digit(c)?, a + b);
+    }
+    let mut part = (BigInt::from(0u8), BigInt::from(0u8));
+    for c in fraction.chars().rev() {
+        let a = part.0 + digit(c)?;
+        part = (part.1 - &a, a);
+    }
+
+    let (a, b) = (value.0 + part.0, value.1 + part.1);
+    if b != BigInt::from(0u8) {
+        return Err(ParseError::NotAnInteger);
+    }
+    u128::try_from(&a).map_err(|_| ParseError::TooBig)
+}
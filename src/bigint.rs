@@ -0,0 +1,329 @@
+use crate::{better_dynamic_fib, MAX_FIB_INDEX_U128};
+use num_bigint::BigUint;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::{Add, Mul};
+use std::path::Path;
+
+#[test]
+fn test_bigint_fib() {
+    for fib_num in 0..=MAX_FIB_INDEX_U128 {
+        assert_eq!(
+            bigint_fib(fib_num),
+            BigUint::from(better_dynamic_fib(fib_num))
+        );
+    }
+    // F(187), the first one that doesn't fit in a u128
+    assert_eq!(
+        bigint_fib(187).to_string(),
+        "538522340430300790495419781092981030533"
+    );
+}
+
+#[test]
+fn test_karatsuba_mul() {
+    let zero = BigUint::from(0u8);
+    assert_eq!(karatsuba_mul(&zero, &bigint_fib(10_000)), zero);
+    for &(m, n) in &[(10, 20), (3_000, 3_000), (10_000, 10_000), (4_000, 20_000)] {
+        let (a, b) = (bigint_fib(m), bigint_fib(n));
+        assert_eq!(karatsuba_mul(&a, &b), &a * &b, "F({}) × F({})", m, n);
+    }
+}
+
+#[test]
+fn test_bigint_matrix_fib() {
+    for fib_num in 0..=300 {
+        assert_eq!(bigint_matrix_fib(fib_num), bigint_fib(fib_num));
+    }
+    assert_eq!(bigint_matrix_fib(20_000), bigint_fib(20_000));
+}
+
+#[test]
+fn test_adaptive_fib() {
+    for n in 0..=186 {
+        assert_eq!(
+            adaptive_fib(n),
+            FibValue::Small(better_dynamic_fib(n as u128))
+        );
+    }
+    for &n in &[185, 186] {
+        assert!(matches!(adaptive_fib(n), FibValue::Small(_)));
+    }
+    for &n in &[187, 188] {
+        assert!(matches!(adaptive_fib(n), FibValue::Big(_)));
+        assert_eq!(adaptive_fib(n), FibValue::Big(bigint_fib(n as u128)));
+        assert_eq!(adaptive_fib(n).to_u128(), None);
+    }
+    assert_eq!(
+        adaptive_fib(187).to_string(),
+        "538522340430300790495419781092981030533"
+    );
+    assert_eq!(adaptive_fib(186).to_u128(), Some(better_dynamic_fib(186)));
+
+    // adding across the boundary moves up to a BigUint by itself
+    assert_eq!(adaptive_fib(185) + adaptive_fib(186), adaptive_fib(187));
+    assert_eq!(adaptive_fib(186) + adaptive_fib(187), adaptive_fib(188));
+    assert_eq!(adaptive_fib(10) + adaptive_fib(11), FibValue::Small(144));
+    // the same value is equal however it's held
+    assert_eq!(FibValue::Big(BigUint::from(55u8)), FibValue::Small(55));
+    // F(2n) = F(n)(F(n - 1) + F(n + 1))
+    assert_eq!(
+        adaptive_fib(100) * (adaptive_fib(99) + adaptive_fib(101)),
+        adaptive_fib(200)
+    );
+    assert_eq!(adaptive_fib(5) * adaptive_fib(6), FibValue::Small(40));
+}
+
+// A Fibonacci number in a u128 when it fits, and a BigUint when it
+// doesn't
+#[derive(Clone, Debug)]
+pub enum FibValue {
+    Small(u128),
+    Big(BigUint),
+}
+
+impl FibValue {
+    pub fn to_u128(&self) -> Option<u128> {
+        match self {
+            FibValue::Small(value) => Some(*value),
+            FibValue::Big(value) => u128::try_from(value).ok(),
+        }
+    }
+
+    pub fn to_biguint(&self) -> BigUint {
+        match self {
+            FibValue::Small(value) => BigUint::from(*value),
+            FibValue::Big(value) => value.clone(),
+        }
+    }
+
+    // Back down to Small if it fits, so equal values look the same
+    fn from_biguint(value: BigUint) -> FibValue {
+        match u128::try_from(&value) {
+            Ok(small) => FibValue::Small(small),
+            Err(_) => FibValue::Big(value),
+        }
+    }
+}
+
+impl PartialEq for FibValue {
+    fn eq(&self, other: &FibValue) -> bool {
+        match (self, other) {
+            (FibValue::Small(a), FibValue::Small(b)) => a == b,
+            _ => self.to_biguint() == other.to_biguint(),
+        }
+    }
+}
+
+impl Eq for FibValue {}
+
+impl fmt::Display for FibValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FibValue::Small(value) => write!(f, "{}", value),
+            FibValue::Big(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+// Staying in u128s for as long as the answer fits
+impl Add for FibValue {
+    type Output = FibValue;
+
+    fn add(self, other: FibValue) -> FibValue {
+        if let (FibValue::Small(a), FibValue::Small(b)) = (&self, &other) {
+            if let Some(sum) = a.checked_add(*b) {
+                return FibValue::Small(sum);
+            }
+        }
+        FibValue::from_biguint(self.to_biguint() + other.to_biguint())
+    }
+}
+
+impl Mul for FibValue {
+    type Output = FibValue;
+
+    fn mul(self, other: FibValue) -> FibValue {
+        if let (FibValue::Small(a), FibValue::Small(b)) = (&self, &other) {
+            if let Some(product) = a.checked_mul(*b) {
+                return FibValue::Small(product);
+            }
+        }
+        FibValue::from_biguint(self.to_biguint() * other.to_biguint())
+    }
+}
+
+// better_dynamic_fib while F(n) fits in a u128, and bigint_fib after
+// that, so nobody has to pick
+pub fn adaptive_fib(n: u64) -> FibValue {
+    if n as u128 <= MAX_FIB_INDEX_U128 {
+        FibValue::Small(better_dynamic_fib(n as u128))
+    } else {
+        FibValue::Big(bigint_fib(n as u128))
+    }
+}
+
+// better_dynamic_fib, but with no ceiling at F(186)
+pub fn bigint_fib(fib_num: u128) -> BigUint {
+    let mut memo = (BigUint::from(0u8), BigUint::from(1u8));
+    match fib_num {
+        0 => memo.0,
+        _ => {
+            for _ in 2..=fib_num {
+                let next = &memo.0 + &memo.1;
+                memo = (memo.1, next);
+            }
+            memo.1
+        }
+    }
+}
+
+// How far bigint_fib_checkpointed had got: a is F(i) and b is F(i + 1)
+#[derive(Debug, PartialEq)]
+pub struct Checkpoint {
+    pub i: u64,
+    pub a: BigUint,
+    pub b: BigUint,
+}
+
+const CHECKPOINT_HEADER: &str = "fibonacci_race checkpoint";
+
+// What's in the checkpoint file, or None if there isn't one yet. It's
+// just text, the header and then i, a and b a line each (a and b in
+// hex to keep them shorter)
+pub fn read_checkpoint(path: &Path) -> io::Result<Option<Checkpoint>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let mut lines = contents.lines();
+    let checkpoint = (|| {
+        if lines.next()? != CHECKPOINT_HEADER {
+            return None;
+        }
+        let i = lines.next()?.parse().ok()?;
+        let a = BigUint::parse_bytes(lines.next()?.as_bytes(), 16)?;
+        let b = BigUint::parse_bytes(lines.next()?.as_bytes(), 16)?;
+        Some(Checkpoint { i, a, b })
+    })();
+    match checkpoint {
+        Some(checkpoint) => Ok(Some(checkpoint)),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} isn't a checkpoint", path.display()),
+        )),
+    }
+}
+
+// Written next to path first and then moved over it, so getting killed
+// halfway through writing leaves the last checkpoint alone instead of
+// half of a new one
+pub fn write_checkpoint(path: &Path, checkpoint: &Checkpoint) -> io::Result<()> {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".partial");
+    fs::write(
+        &partial,
+        format!(
+            "{}\n{}\n{:x}\n{:x}\n",
+            CHECKPOINT_HEADER, checkpoint.i, checkpoint.a, checkpoint.b
+        ),
+    )?;
+    fs::rename(&partial, path)
+}
+
+// bigint_fib, saving where it's got to every checkpoint_every steps and
+// picking up from there if checkpoint_path is already there, so a big n
+// that gets interrupted doesn't have to start over. F(i) and F(i + 1)
+// are all it needs and they don't depend on n, so a checkpoint from a
+// smaller n is a head start too. One from further along than n is no
+// use (it can't go backwards) and is left alone
+pub fn bigint_fib_checkpointed(
+    n: u64,
+    checkpoint_path: &Path,
+    checkpoint_every: u64,
+) -> io::Result<BigUint> {
+    assert!(
+        checkpoint_every > 0,
+        "checkpoint_every has to be at least 1"
+    );
+    let mut state = match read_checkpoint(checkpoint_path)? {
+        Some(checkpoint) if checkpoint.i <= n => checkpoint,
+        Some(_) => return Ok(bigint_fib(n as u128)),
+        None => Checkpoint {
+            i: 0,
+            a: BigUint::from(0u8),
+            b: BigUint::from(1u8),
+        },
+    };
+    while state.i < n {
+        let next = &state.a + &state.b;
+        state.a = std::mem::replace(&mut state.b, next);
+        state.i += 1;
+        if state.i.is_multiple_of(checkpoint_every) {
+            write_checkpoint(checkpoint_path, &state)?;
+        }
+    }
+    Ok(state.a)
+}
+
+// Below this many bits karatsuba_mul just uses num-bigint's multiply,
+// splitting numbers that small costs more than it saves
+const KARATSUBA_CUTOFF_BITS: u64 = 2048;
+
+// Karatsuba: split each number in half at the same bit, a = a1·2^k + a0
+// and b = b1·2^k + b0, and then
+//   a·b = a1·b1·2^2k + ((a0 + a1)(b0 + b1) - a0·b0 - a1·b1)·2^k + a0·b0
+// which is three half size multiplies instead of the four schoolbook
+// takes. num-bigint already switches to Karatsuba (and Toom-3) by itself
+// for big enough numbers, so this is here to compare against that, not
+// to beat it
+pub fn karatsuba_mul(a: &BigUint, b: &BigUint) -> BigUint {
+    if a.bits().min(b.bits()) <= KARATSUBA_CUTOFF_BITS {
+        return a * b;
+    }
+    let half = a.bits().max(b.bits()) / 2;
+    let mask = (BigUint::from(1u8) << half) - 1u8;
+    let (a1, a0) = (a >> half, a & &mask);
+    let (b1, b0) = (b >> half, b & &mask);
+    let low = karatsuba_mul(&a0, &b0);
+    let high = karatsuba_mul(&a1, &b1);
+    let middle = karatsuba_mul(&(a0 + a1), &(b0 + b1)) - &low - &high;
+    (high << (2 * half)) + (middle << half) + low
+}
+
+// karatsuba_mul with the karatsuba feature, num-bigint's own without
+fn multiply(a: &BigUint, b: &BigUint) -> BigUint {
+    if cfg!(feature = "karatsuba") {
+        karatsuba_mul(a, b)
+    } else {
+        a * b
+    }
+}
+
+type Matrix = [[BigUint; 2]; 2];
+
+fn matrix_multiply(x: &Matrix, y: &Matrix) -> Matrix {
+    let cell = |i: usize, j: usize| multiply(&x[i][0], &y[0][j]) + multiply(&x[i][1], &y[1][j]);
+    [[cell(0, 0), cell(0, 1)], [cell(1, 0), cell(1, 1)]]
+}
+
+// [[1, 1], [1, 0]]^n is [[F(n + 1), F(n)], [F(n), F(n - 1)]], squared up
+// one bit of n at a time. It's all multiplying numbers about as big as
+// F(n), so how good the multiply is decides how fast this is
+pub fn bigint_matrix_fib(mut fib_num: u128) -> BigUint {
+    let one = || BigUint::from(1u8);
+    let zero = || BigUint::from(0u8);
+    let mut result: Matrix = [[one(), zero()], [zero(), one()]];
+    let mut base: Matrix = [[one(), one()], [one(), zero()]];
+    while fib_num > 0 {
+        if fib_num & 1 == 1 {
+            result = matrix_multiply(&result, &base);
+        }
+        base = matrix_multiply(&base, &base);
+        fib_num >>= 1;
+    }
+    result[0][1].clone()
+}
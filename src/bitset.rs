@@ -0,0 +1,98 @@
+use crate::lookup_fib;
+
+#[test]
+fn test_fib_bitset() {
+    let mut set = FibBitset::new();
+    assert!(set.is_empty());
+    for &k in &[0, 5, 63, 64, 100, 186] {
+        set.insert(k);
+    }
+    set.insert(5);
+    assert!(set.contains(63) && set.contains(64) && !set.contains(65));
+    assert!(!set.contains(1000));
+    assert_eq!(set.iter_set().collect::<Vec<_>>(), [0, 5, 63, 64, 100, 186]);
+
+    set.remove(63);
+    set.remove(0);
+    set.remove(1000);
+    assert_eq!(set.len(), 4);
+    let values: Vec<u128> = set.clone().into_values().collect();
+    assert_eq!(
+        values,
+        [5, lookup_fib(64), lookup_fib(100), lookup_fib(186)]
+    );
+
+    let round_trip: FibBitset = {
+        let mut other = FibBitset::new();
+        for k in set.iter_set() {
+            other.insert(k);
+        }
+        other
+    };
+    assert_eq!(round_trip, set);
+}
+
+// A set of Fibonacci positions (so k means F(k), not the number k) with
+// one bit per position. All 187 positions that fit in a u128 take up
+// just three u64s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FibBitset {
+    data: Vec<u64>,
+}
+
+impl FibBitset {
+    pub fn new() -> FibBitset {
+        FibBitset::default()
+    }
+
+    fn word_and_bit(k: u128) -> (usize, u64) {
+        ((k / 64) as usize, 1 << (k % 64))
+    }
+
+    pub fn insert(&mut self, k: u128) {
+        let (word, bit) = FibBitset::word_and_bit(k);
+        if word >= self.data.len() {
+            self.data.resize(word + 1, 0);
+        }
+        self.data[word] |= bit;
+    }
+
+    pub fn contains(&self, k: u128) -> bool {
+        let (word, bit) = FibBitset::word_and_bit(k);
+        self.data.get(word).is_some_and(|&data| data & bit != 0)
+    }
+
+    pub fn remove(&mut self, k: u128) {
+        let (word, bit) = FibBitset::word_and_bit(k);
+        if let Some(data) = self.data.get_mut(word) {
+            *data &= !bit;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data
+            .iter()
+            .map(|data| data.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(|&data| data == 0)
+    }
+
+    // Every position in the set, smallest first
+    pub fn iter_set(&self) -> impl Iterator<Item = u128> + '_ {
+        self.data.iter().enumerate().flat_map(|(word, &data)| {
+            (0..64)
+                .filter(move |bit| data & (1 << bit) != 0)
+                .map(move |bit| word as u128 * 64 + bit)
+        })
+    }
+
+    // The Fibonacci numbers themselves, F(k) for every k in the set (any
+    // k past 186 won't fit in a u128, and panics)
+    pub fn into_values(self) -> impl Iterator<Item = u128> {
+        let positions: Vec<u128> = self.iter_set().collect();
+        positions.into_iter().map(lookup_fib)
+    }
+}
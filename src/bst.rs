@@ -0,0 +1,95 @@
+use crate::iter::fibonacci_iter;
+use crate::MAX_FIB_INDEX_U128;
+
+#[test]
+fn test_build_fib_bst() {
+    // F(0) through F(20) is 20 different numbers (1 is there twice),
+    // and a binary tree of 20 needs 5 levels
+    let tree = build_fib_bst(20).unwrap();
+    assert_eq!(tree.height(), 5);
+    for value in fibonacci_iter().take(21) {
+        assert!(tree.search(value), "{} is missing", value);
+    }
+    for &value in &[4, 6, 7, 6764, 6766, 10946] {
+        assert!(!tree.search(value), "{} shouldn't be there", value);
+    }
+    let mut keys = Vec::new();
+    tree.in_order(&mut keys);
+    assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(keys.len(), 20);
+
+    assert_eq!(build_fib_bst(0).unwrap().height(), 1);
+    assert_eq!(build_fib_bst(MAX_FIB_INDEX_U128).unwrap().height(), 8);
+}
+
+pub struct BstNode {
+    pub key: u128,
+    pub left: Option<Box<BstNode>>,
+    pub right: Option<Box<BstNode>>,
+}
+
+impl BstNode {
+    pub fn search(&self, key: u128) -> bool {
+        let mut node = self;
+        loop {
+            let next = if key < node.key {
+                &node.left
+            } else if key > node.key {
+                &node.right
+            } else {
+                return true;
+            };
+            match next {
+                Some(child) => node = child,
+                None => return false,
+            }
+        }
+    }
+
+    // Counting nodes, so a tree of just the root is 1 high
+    pub fn height(&self) -> usize {
+        let left = self.left.as_ref().map_or(0, |left| left.height());
+        let right = self.right.as_ref().map_or(0, |right| right.height());
+        1 + left.max(right)
+    }
+
+    // Every key, smallest first
+    pub fn in_order(&self, keys: &mut Vec<u128>) {
+        if let Some(left) = &self.left {
+            left.in_order(keys);
+        }
+        keys.push(self.key);
+        if let Some(right) = &self.right {
+            right.in_order(keys);
+        }
+    }
+}
+
+// The middle key at the top and each half built the same way under it,
+// which splits what's left as evenly as it can at every level, so the
+// tree is as short as any tree of that many keys can be:
+// ceil(log2(keys + 1)) levels
+fn build_balanced(keys: &[u128]) -> Option<Box<BstNode>> {
+    if keys.is_empty() {
+        return None;
+    }
+    let middle = keys.len() / 2;
+    Some(Box::new(BstNode {
+        key: keys[middle],
+        left: build_balanced(&keys[..middle]),
+        right: build_balanced(&keys[middle + 1..]),
+    }))
+}
+
+// A search tree of F(0) through F(max_index), with the 1 that's in
+// there twice only getting the one node
+pub fn build_fib_bst(max_index: u128) -> Option<Box<BstNode>> {
+    assert!(
+        max_index <= MAX_FIB_INDEX_U128,
+        "F({}) doesn't fit in a u128",
+        max_index
+    );
+    let mut keys: Vec<u128> = fibonacci_iter().take(max_index as usize + 1).collect();
+    keys.dedup();
+    build_balanced(&keys)
+}
@@ -0,0 +1,134 @@
+use crate::{lookup_fib, MAX_FIB_INDEX_U128};
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+#[test]
+fn test_fibonacci_cache() {
+    let mut cache = FibonacciCache::new();
+    assert_eq!(cache.capacity(), 5);
+    for key in 0..5 {
+        cache.insert(key, key * 10);
+    }
+    // 0 gets used, so 1 is the least recently used now
+    assert_eq!(cache.get(&0), Some(&0));
+    cache.insert(5, 50);
+    assert_eq!(cache.get(&1), None);
+    cache.insert(2, 21);
+    cache.insert(6, 60);
+    assert_eq!(cache.get(&3), None);
+    assert_eq!(cache.get(&2), Some(&21));
+    assert_eq!(cache.len(), 5);
+
+    let capacities: Vec<u128> = (0..4)
+        .map(|_| {
+            cache.grow();
+            cache.capacity()
+        })
+        .collect();
+    assert_eq!(capacities, [8, 13, 21, 34]);
+    for key in 100..129 {
+        cache.insert(key, key);
+    }
+    assert_eq!(cache.len(), 34);
+    assert_eq!(cache.get(&0), Some(&0));
+    assert_eq!(cache.get(&100), Some(&100));
+
+    // F(186) is as big as a capacity gets, it stays there after that
+    for _ in 0..200 {
+        cache.grow();
+    }
+    assert_eq!(cache.capacity(), lookup_fib(MAX_FIB_INDEX_U128));
+}
+
+// A least recently used cache that holds F(k) entries, starting at 5
+// and going up to 8, 13, 21... each time it grows (as far as F(186)).
+// That grows about 1.6x at a time, which wastes less than doubling when
+// the cache is big. Looking something up counts as using it, so get has
+// to be able to update when each entry was last used. Every use gets
+// the next number from a counter, and order has the entries by that
+// number, so the least recently used is always the first one there
+pub struct FibonacciCache<K: Hash + Eq, V> {
+    // each value with the number of its last use
+    map: HashMap<K, (V, Cell<u64>)>,
+    capacity: u128,
+    fib_index: u128,
+    // last use to key, oldest first
+    order: RefCell<BTreeMap<u64, K>>,
+    next_use: Cell<u64>,
+}
+
+impl<K: Hash + Eq + Clone, V> Default for FibonacciCache<K, V> {
+    fn default() -> Self {
+        FibonacciCache::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> FibonacciCache<K, V> {
+    pub fn new() -> FibonacciCache<K, V> {
+        FibonacciCache {
+            map: HashMap::new(),
+            capacity: lookup_fib(5),
+            fib_index: 5,
+            order: RefCell::new(BTreeMap::new()),
+            next_use: Cell::new(0),
+        }
+    }
+
+    pub fn capacity(&self) -> u128 {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    fn next_use(&self) -> u64 {
+        let stamp = self.next_use.get();
+        self.next_use.set(stamp + 1);
+        stamp
+    }
+
+    // Make last_use the most recent one
+    fn touch(&self, last_use: &Cell<u64>) {
+        let stamp = self.next_use();
+        let mut order = self.order.borrow_mut();
+        let key = order.remove(&last_use.get()).unwrap();
+        order.insert(stamp, key);
+        last_use.set(stamp);
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let (value, last_use) = self.map.get(key)?;
+        self.touch(last_use);
+        Some(value)
+    }
+
+    // Out goes the least recently used entry if there's no room
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some((old, _)) = self.map.get_mut(&key) {
+            *old = value;
+            self.touch(&self.map[&key].1);
+            return;
+        }
+        let stamp = self.next_use();
+        self.order.get_mut().insert(stamp, key.clone());
+        self.map.insert(key, (value, Cell::new(stamp)));
+        if self.map.len() as u128 > self.capacity {
+            let (_, oldest) = self.order.get_mut().pop_first().unwrap();
+            self.map.remove(&oldest);
+        }
+    }
+
+    // On to the next Fibonacci capacity, unless it's already F(186)
+    pub fn grow(&mut self) {
+        if self.fib_index < MAX_FIB_INDEX_U128 {
+            self.fib_index += 1;
+            self.capacity = lookup_fib(self.fib_index);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::iter::fibonacci_iter;
+
+#[test]
+fn test_fibonacci_checksum() {
+    assert_eq!(fibonacci_checksum(&[]), 0);
+    // 1×1 ^ 2×1 ^ 3×2 = 1 ^ 2 ^ 6
+    assert_eq!(fibonacci_checksum(&[1, 2, 3]), 5);
+
+    // long enough for the weights to wrap back around to F(1)
+    let data = crate::datagen::generate_test_data(200);
+    let checksum = fibonacci_checksum(&data);
+    for i in 0..data.len() {
+        for byte in 0..=255 {
+            if byte == data[i] {
+                continue;
+            }
+            let mut changed = data.clone();
+            changed[i] = byte;
+            assert_ne!(
+                fibonacci_checksum(&changed),
+                checksum,
+                "byte {} to {}",
+                i,
+                byte
+            );
+        }
+    }
+}
+
+// Each byte times the next Fibonacci number, F(1) for the first byte,
+// F(2) for the second and so on (starting over at F(1) after F(186)),
+// all XORed together. Changing any one byte always changes the checksum,
+// since no Fibonacci number has enough factors of 2 for the difference
+// to wrap to 0. Two or more changes can cancel out, though, and it's
+// only a checksum: by the birthday paradox random inputs start colliding
+// after around 2^64 of them, and unlike CRC32 (which catches every burst
+// error up to 32 bits, and lets a random change through about 1 time in
+// 2^32) there's no guarantee about changed runs of bytes. Bytes only move
+// the low bits of the small weights, so the early part of the checksum
+// mixes a lot less than CRC32 does
+pub fn fibonacci_checksum(data: &[u8]) -> u128 {
+    let weights = std::iter::repeat_with(|| fibonacci_iter().skip(1)).flatten();
+    data.iter()
+        .zip(weights)
+        .fold(0, |checksum, (&byte, weight)| {
+            checksum ^ weight.wrapping_mul(byte as u128)
+        })
+}
@@ -0,0 +1,71 @@
+use crate::iter::{fibonacci_iter_from, FibIterFrom};
+use crate::MAX_FIB_INDEX_U128;
+
+#[test]
+fn test_fib_stream_cipher() {
+    let data = b"Fibonacci numbers are not a secret".to_vec();
+    let encrypted = FibStreamCipher::new(10).encrypt(&data);
+    assert_ne!(encrypted, data);
+    assert_eq!(encrypted[0], data[0] ^ 55);
+    assert_eq!(FibStreamCipher::new(10).decrypt(&encrypted), data);
+
+    // the same cipher twice keeps going along the key stream, so the
+    // second pass doesn't undo the first
+    let mut cipher = FibStreamCipher::new(10);
+    let once = cipher.encrypt(&data);
+    assert_ne!(cipher.encrypt(&once), data);
+
+    // longer than the key stream, so it starts over partway through
+    let long: Vec<u8> = (0..=255).cycle().take(1000).collect();
+    let encrypted = FibStreamCipher::new(0).encrypt(&long);
+    assert_eq!(FibStreamCipher::new(0).decrypt(&encrypted), long);
+    // encrypting 0s gives the key stream itself
+    let key_stream = FibStreamCipher::new(0).encrypt(&[0; 374]);
+    assert_eq!(key_stream[187..], key_stream[..187]);
+}
+
+// NOT SECURE, DO NOT USE THIS TO PROTECT ANYTHING. It's a demonstration
+// of how a stream cipher works, XORing each byte with the next byte of a
+// key stream, and the key stream here is just F(seed_n), F(seed_n + 1),
+// ... mod 256. Anyone can work that out from a few bytes of known
+// plaintext, there are only 187 possible seeds to try, and after F(186)
+// it starts over from F(seed_n), so the key stream repeats too
+pub struct FibStreamCipher {
+    key_stream: FibIterFrom,
+    seed_n: u128,
+}
+
+impl FibStreamCipher {
+    pub fn new(seed_n: u128) -> FibStreamCipher {
+        assert!(
+            seed_n <= MAX_FIB_INDEX_U128,
+            "F({}) doesn't fit in a u128",
+            seed_n
+        );
+        FibStreamCipher {
+            key_stream: fibonacci_iter_from(seed_n),
+            seed_n,
+        }
+    }
+
+    fn next_key(&mut self) -> u8 {
+        let fib = match self.key_stream.next() {
+            Some(fib) => fib,
+            None => {
+                self.key_stream = fibonacci_iter_from(self.seed_n);
+                self.key_stream.next().unwrap()
+            }
+        };
+        (fib % 256) as u8
+    }
+
+    pub fn encrypt(&mut self, data: &[u8]) -> Vec<u8> {
+        data.iter().map(|&byte| byte ^ self.next_key()).collect()
+    }
+
+    // XOR undoes itself, so it's the same as encrypting (starting from
+    // the same point in the key stream)
+    pub fn decrypt(&mut self, data: &[u8]) -> Vec<u8> {
+        self.encrypt(data)
+    }
+}
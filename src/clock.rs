@@ -0,0 +1,148 @@
+#[test]
+fn test_fibonacci_clock() {
+    use Color::*;
+
+    assert_eq!(fibonacci_clock_encode(1, 5), [Off, Purple, Off, Off, Off]);
+    assert_eq!(fibonacci_clock_encode(12, 0), [Red; 5]);
+    assert_eq!(fibonacci_clock_encode(0, 0), [Red; 5]);
+    assert_eq!(fibonacci_clock_encode(7, 40), [Off, Off, Red, Blue, Purple]);
+    assert_eq!(
+        fibonacci_clock_encode(19, 43),
+        [Off, Off, Red, Blue, Purple]
+    );
+    assert_eq!(
+        fibonacci_clock_encode(4, 55),
+        [Off, Purple, Blue, Purple, Blue]
+    );
+
+    for hours in 1..=12 {
+        for minutes in (0..60).step_by(5) {
+            let colors = fibonacci_clock_encode(hours, minutes);
+            assert_eq!(fibonacci_clock_decode(&colors), Some((hours, minutes)));
+        }
+    }
+    assert_eq!(fibonacci_clock_decode(&[Blue; 5]), None);
+    assert_eq!(fibonacci_clock_decode(&[Off; 5]), None);
+}
+
+#[test]
+fn test_render_clock() {
+    let noon = render_clock(12, 0);
+    let grid: Vec<&str> = noon.lines().take(LAYOUT.len()).collect();
+    assert_eq!(grid, ["RRRRRRRR"; 5]);
+    for code in &["R", "B", "P", "_"] {
+        assert!(noon.contains(code), "legend is missing {}", code);
+    }
+
+    let grid = render_clock(4, 55);
+    let grid: Vec<&str> = grid.lines().take(LAYOUT.len()).collect();
+    assert_eq!(
+        grid,
+        ["BB_BBBBB", "BBPBBBBB", "PPPBBBBB", "PPPBBBBB", "PPPBBBBB"]
+    );
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Off,
+    Red,
+    Blue,
+    Purple,
+}
+
+// The sides of the five squares, F(1) through F(5), which add up to 12
+pub const SQUARES: [u8; 5] = [1, 1, 2, 3, 5];
+
+// Where each square sits on the face, by its index in SQUARES: the two
+// 1s and the 2 stacked on the 3, all of them next to the 5
+const LAYOUT: [[usize; 8]; 5] = [
+    [2, 2, 0, 4, 4, 4, 4, 4],
+    [2, 2, 1, 4, 4, 4, 4, 4],
+    [3, 3, 3, 4, 4, 4, 4, 4],
+    [3, 3, 3, 4, 4, 4, 4, 4],
+    [3, 3, 3, 4, 4, 4, 4, 4],
+];
+
+impl Color {
+    pub fn code(self) -> char {
+        match self {
+            Color::Off => '_',
+            Color::Red => 'R',
+            Color::Blue => 'B',
+            Color::Purple => 'P',
+        }
+    }
+}
+
+// Which squares add up to value (at most 12), taking the biggest ones
+// that fit first so the same time always lights up the same way
+fn squares_for(mut value: u8) -> [bool; 5] {
+    let mut lit = [false; 5];
+    for (square, &side) in SQUARES.iter().enumerate().rev() {
+        if side <= value {
+            lit[square] = true;
+            value -= side;
+        }
+    }
+    lit
+}
+
+// A twelve hour Fibonacci clock: the hour is the sum of the red and
+// purple squares, and the minutes (in steps of 5, rounded down) are the
+// sum of the blue and purple ones. Midnight and noon light up every
+// square red
+pub fn fibonacci_clock_encode(hours: u8, minutes: u8) -> [Color; 5] {
+    assert!(hours < 24 && minutes < 60, "that isn't a time of day");
+    let hours = match hours % 12 {
+        0 => 12,
+        hours => hours,
+    };
+    let hour_squares = squares_for(hours);
+    let minute_squares = squares_for(minutes / 5);
+
+    let mut colors = [Color::Off; 5];
+    for square in 0..SQUARES.len() {
+        colors[square] = match (hour_squares[square], minute_squares[square]) {
+            (true, true) => Color::Purple,
+            (true, false) => Color::Red,
+            (false, true) => Color::Blue,
+            (false, false) => Color::Off,
+        };
+    }
+    colors
+}
+
+// Back to (hours, minutes), or None if the colors don't make a time
+// (no hour at all, or all 60 minutes)
+pub fn fibonacci_clock_decode(colors: &[Color; 5]) -> Option<(u8, u8)> {
+    let mut hours = 0;
+    let mut minutes = 0;
+    for (color, &side) in colors.iter().zip(&SQUARES) {
+        match color {
+            Color::Red => hours += side,
+            Color::Blue => minutes += side,
+            Color::Purple => {
+                hours += side;
+                minutes += side;
+            }
+            Color::Off => {}
+        }
+    }
+    if hours == 0 || minutes == 12 {
+        return None;
+    }
+    Some((hours, minutes * 5))
+}
+
+// The clock face drawn a letter per cell, with a legend under it. The
+// five squares tile an 8 by 5 rectangle, the same shape as the real clock
+pub fn render_clock(hours: u8, minutes: u8) -> String {
+    let colors = fibonacci_clock_encode(hours, minutes);
+    let mut face = String::new();
+    for row in &LAYOUT {
+        face.extend(row.iter().map(|&square| colors[square].code()));
+        face.push('\n');
+    }
+    face.push_str("R = hours, B = minutes, P = both, _ = off");
+    face
+}
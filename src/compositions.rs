@@ -0,0 +1,79 @@
+use crate::iter::fibonacci_iter;
+
+#[test]
+fn test_fib_compositions() {
+    let known = [
+        1, 1, 2, 4, 7, 14, 26, 49, 94, 177, 336, 637, 1206, 2288, 4335, 8216,
+    ];
+    for (n, &ways) in known.iter().enumerate() {
+        assert_eq!(fib_compositions(n as u128), Some(ways));
+    }
+    assert!(fib_compositions(139).is_some());
+    assert_eq!(fib_compositions(140), None);
+}
+
+#[test]
+fn test_split_fib() {
+    assert_eq!(split_fib(8), Some((3, 5)));
+    assert_eq!(split_fib(2), Some((1, 1)));
+    // 9 splits fine as 1 + 8, but nothing adds up to 12
+    assert_eq!(split_fib(9), Some((1, 8)));
+    assert_eq!(split_fib(12), None);
+    assert_eq!(split_fib(1), None);
+    let biggest = crate::better_dynamic_fib(186);
+    assert_eq!(
+        split_fib(biggest),
+        Some((
+            crate::better_dynamic_fib(184),
+            crate::better_dynamic_fib(185)
+        ))
+    );
+}
+
+// How many ways n can be written as an ordered sum of Fibonacci numbers
+// (so 3 = 3 = 2 + 1 = 1 + 2 = 1 + 1 + 1 is 4 ways). The last part of the
+// sum can be any Fibonacci number f that fits, leaving n - f to be
+// written any way at all, so ways[n] is the sum of ways[n - f]. The
+// answer grows about 1.88x with each n, and by 140 it is too big for a
+// u128, so that's a None
+pub fn fib_compositions(n: u128) -> Option<u128> {
+    // skip F(0) (no progress) and F(1) (same as F(2))
+    let parts: Vec<u128> = fibonacci_iter()
+        .skip(2)
+        .take_while(|&part| part <= n)
+        .collect();
+
+    let mut ways = vec![1u128];
+    for k in 1..=n {
+        let mut total: u128 = 0;
+        for &part in parts.iter().take_while(|&&part| part <= k) {
+            total = total.checked_add(ways[(k - part) as usize])?;
+        }
+        ways.push(total);
+    }
+    ways.last().copied()
+}
+
+// Two Fibonacci numbers (smallest first) that add up to n, if there are
+// any. Walks in from both ends of the Fibonacci numbers up to n: too big
+// a sum means the big one has to shrink, too small means the small one
+// has to grow. F(0) is left out, or every Fibonacci n would just be 0 + n
+pub fn split_fib(n: u128) -> Option<(u128, u128)> {
+    let fibs: Vec<u128> = fibonacci_iter()
+        .skip(1)
+        .take_while(|&fib| fib <= n)
+        .collect();
+    if fibs.is_empty() {
+        return None;
+    }
+
+    let (mut low, mut high) = (0, fibs.len() - 1);
+    while low <= high {
+        match fibs[low].checked_add(fibs[high]) {
+            Some(sum) if sum == n => return Some((fibs[low], fibs[high])),
+            Some(sum) if sum < n => low += 1,
+            _ => high = high.checked_sub(1)?,
+        }
+    }
+    None
+}
@@ -0,0 +1,30 @@
+use crate::FIB_TABLE;
+
+#[test]
+fn test_ctfib() {
+    for n in 0..FIB_TABLE.len() as u128 {
+        assert_eq!(ctfib(n), Some(crate::lookup_fib(n)));
+    }
+    assert_eq!(ctfib(187), None);
+    assert_eq!(ctfib(u128::MAX), None);
+}
+
+// F(n) from FIB_TABLE without branching on n or letting n pick which
+// memory gets read. Indexing straight into the table would read a
+// different cache line depending on n, which is as much of a leak as a
+// branch, so every entry gets read every time and the one that matches
+// is kept with a mask: all 1s when i == n and all 0s otherwise, made by
+// arithmetic on the comparison rather than an if. The loop runs the
+// same 187 times for any n, and there's nothing data dependent in it.
+// The only thing that depends on n is whether the answer is Some, which
+// just says whether n was in range
+pub fn ctfib(n: u128) -> Option<u128> {
+    let mut value = 0u128;
+    let mut found = 0u128;
+    for (i, &fib) in FIB_TABLE.iter().enumerate() {
+        let mask = 0u128.wrapping_sub((i as u128 == n) as u128);
+        value |= fib & mask;
+        found |= mask;
+    }
+    (found != 0).then_some(value)
+}
@@ -0,0 +1,72 @@
+use crate::iter::fibonacci_iter;
+
+#[test]
+fn test_fib_gap_ratios() {
+    let ratios = fib_gap_ratios(5);
+    assert_eq!(ratios, [1.0, 2.0, 1.5, 5.0 / 3.0, 1.6]);
+    let errors = fib_gap_percent_error(30);
+    assert_eq!(errors.len(), 30);
+    assert!(errors.windows(2).all(|pair| pair[1] < pair[0]));
+    assert!(*fib_gap_percent_error(50).last().unwrap() < 1e-10);
+}
+
+#[test]
+fn test_reciprocal_fibonacci_sum() {
+    assert_eq!(reciprocal_fibonacci_sum(0), 0.0);
+    assert_eq!(reciprocal_fibonacci_sum(4), 1.0 + 1.0 + 0.5 + 1.0 / 3.0);
+    assert!((reciprocal_fibonacci_sum(50) - RECIPROCAL_FIBONACCI_CONSTANT).abs() < 1e-6);
+    for terms in 5..60 {
+        let error = RECIPROCAL_FIBONACCI_CONSTANT - reciprocal_fibonacci_sum(terms);
+        let estimate = reciprocal_fibonacci_sum_error(terms);
+        assert!(
+            (error - estimate).abs() < error * 0.01 + 1e-15,
+            "{} terms",
+            terms
+        );
+    }
+}
+
+// (1 + √5) / 2
+pub const PHI: f64 = 1.618_033_988_749_895;
+
+// F(k + 1) / F(k) for k from 1 to n. They bounce back and forth over φ,
+// closing in on it about φ^2 times faster every step
+pub fn fib_gap_ratios(n: usize) -> Vec<f64> {
+    let fibs: Vec<u128> = fibonacci_iter().skip(1).take(n + 1).collect();
+    fibs.windows(2)
+        .map(|pair| pair[1] as f64 / pair[0] as f64)
+        .collect()
+}
+
+// How far off each of those ratios is from φ, as a percent of φ. By
+// around k = 40 they're as close as an f64 can tell
+pub fn fib_gap_percent_error(n: usize) -> Vec<f64> {
+    fib_gap_ratios(n)
+        .into_iter()
+        .map(|ratio| (ratio - PHI).abs() / PHI * 100.0)
+        .collect()
+}
+
+// Σ 1/F(n) for n from 1 on, which isn't known to have a closed form
+// (it was proven irrational in 1989, though)
+pub const RECIPROCAL_FIBONACCI_CONSTANT: f64 = 3.359_885_666_243_177_5;
+
+// 1/F(1) + 1/F(2) + ... + 1/F(terms). Past F(186) there's nothing left
+// to add, but by then each term is far too small for an f64 to notice
+pub fn reciprocal_fibonacci_sum(terms: u32) -> f64 {
+    fibonacci_iter()
+        .skip(1)
+        .take(terms as usize)
+        .map(|fib| 1.0 / fib as f64)
+        .sum()
+}
+
+// How much is still missing after that many terms. Every term past
+// there is about 1/φ of the one before, so the rest adds up to about
+// the next term times 1 + 1/φ + 1/φ^2 + ..., which is φ^2
+pub fn reciprocal_fibonacci_sum_error(terms: u32) -> f64 {
+    match fibonacci_iter().nth(terms as usize + 1) {
+        Some(next) => PHI * PHI / next as f64,
+        None => 0.0,
+    }
+}
@@ -0,0 +1,93 @@
+use crate::iter::FibIter;
+
+#[test]
+fn test_generate_test_data() {
+    assert_eq!(generate_test_data(10), [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    let data = generate_test_data(400);
+    // F(13) = 233 is the last one under 256, F(14) = 377 wraps to 121
+    assert_eq!(data[13], 233);
+    assert_eq!(data[14], 121);
+    // and it starts over after F(186)
+    assert_eq!(&data[187..197], &data[..10]);
+    assert_eq!(generate_test_data(400), data);
+}
+
+#[test]
+fn test_to_base64() {
+    assert_eq!(to_base64(b""), "");
+    assert_eq!(to_base64(b"f"), "Zg==");
+    assert_eq!(to_base64(b"fo"), "Zm8=");
+    assert_eq!(to_base64(b"foo"), "Zm9v");
+    assert_eq!(to_base64(b"foobar"), "Zm9vYmFy");
+    assert_eq!(to_base64(&[0, 1, 1, 2, 3, 5]), "AAEBAgMF");
+}
+
+// Bytes that look random enough for a test but come out the same every
+// time: F(0), F(1), F(2)... mod 256, starting back at F(0) once FibIter
+// runs out after F(186). So it repeats every 187 bytes, which is plenty
+// for test data and nowhere near good enough for anything else
+pub struct FibDataGen {
+    state: FibIter,
+}
+
+impl FibDataGen {
+    pub fn new() -> FibDataGen {
+        FibDataGen {
+            state: FibIter::new(),
+        }
+    }
+}
+
+impl Default for FibDataGen {
+    fn default() -> Self {
+        FibDataGen::new()
+    }
+}
+
+impl Iterator for FibDataGen {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let fib = match self.state.next() {
+            Some(fib) => fib,
+            None => {
+                self.state = FibIter::new();
+                self.state.next().unwrap()
+            }
+        };
+        Some((fib % 256) as u8)
+    }
+}
+
+// The most --gen-data will make, 64MB (and as hex or base64 that gets
+// turned into an even bigger string before any of it is printed)
+pub const MAX_GEN_DATA_LEN: usize = 1 << 26;
+
+pub fn generate_test_data(len: usize) -> Vec<u8> {
+    FibDataGen::new().take(len).collect()
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+// Standard base64 with = padding, three bytes to four characters
+pub fn to_base64(data: &[u8]) -> String {
+    let mut encoded = String::new();
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let bits = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (bits >> (18 - 6 * i)) & 0x3f;
+                encoded.push(BASE64_ALPHABET[index as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
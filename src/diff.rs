@@ -0,0 +1,83 @@
+use crate::iter::fibonacci_iter;
+
+#[test]
+fn test_fibonacci_diff_sequence() {
+    let diffs = fibonacci_diff_sequence(10);
+    assert_eq!(diffs, [1, 0, 1, 1, 2, 3, 5, 8, 13, 21]);
+    // F(n + 1) - F(n) is F(n - 1), so it's the sequence itself one step back
+    let fibs: Vec<u128> = fibonacci_iter().take(186).collect();
+    assert_eq!(&fibonacci_diff_sequence(186)[1..], &fibs[..185]);
+
+    let second = fibonacci_second_diff_sequence(10);
+    assert_eq!(second, [-1, 1, 0, 1, 1, 2, 3, 5, 8, 13]);
+    assert_eq!(fibonacci_second_diff_sequence(185).len(), 185);
+}
+
+#[test]
+fn test_fibonacci_difference_table() {
+    let table = fibonacci_difference_table(5);
+    assert_eq!(
+        table,
+        vec![
+            vec![0, 1, 1, 2, 3, 5],
+            vec![1, 0, 1, 1, 2],
+            vec![-1, 1, 0, 1],
+            vec![2, -1, 1],
+            vec![-3, 2],
+            vec![5],
+        ]
+    );
+    // each row is the one above it shifted one step back
+    let table = fibonacci_difference_table(MAX_DIFF_TABLE_N);
+    for (k, row) in table.iter().enumerate() {
+        assert_eq!(row.len(), MAX_DIFF_TABLE_N + 1 - k);
+        for (j, &value) in row.iter().enumerate() {
+            assert_eq!(value, crate::signed_fib(j as i64 - k as i64));
+        }
+    }
+}
+
+// F(185) doesn't fit in an i128
+pub const MAX_DIFF_TABLE_N: usize = 184;
+
+// Row 0 is F(0) through F(n), and each row after that is the first
+// differences of the row above, one shorter each time until there's a
+// single number left. F(j + 1) - F(j) = F(j - 1), so row k is the
+// sequence shifted k back, starting at F(-k) (where the signs start
+// alternating)
+pub fn fibonacci_difference_table(n: usize) -> Vec<Vec<i128>> {
+    assert!(n <= MAX_DIFF_TABLE_N, "F({}) doesn't fit in an i128", n);
+    let mut table: Vec<Vec<i128>> = vec![fibonacci_iter()
+        .take(n + 1)
+        .map(|fib| fib as i128)
+        .collect()];
+    while table.last().unwrap().len() > 1 {
+        let row = table
+            .last()
+            .unwrap()
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+        table.push(row);
+    }
+    table
+}
+
+// The first differences F(n + 1) - F(n), for n from 0 to len - 1. Since
+// F(n + 1) = F(n) + F(n - 1) these are just F(n - 1), which makes the
+// first one F(-1) = 1. Works up to len = 186, when F(len) would overflow
+pub fn fibonacci_diff_sequence(len: usize) -> Vec<u128> {
+    let fibs: Vec<u128> = fibonacci_iter().take(len + 1).collect();
+    assert_eq!(fibs.len(), len + 1, "F({}) doesn't fit in a u128", len);
+    fibs.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+// Differences of the differences, F(n - 2). Starting at n = 0 means
+// F(-2) and F(-1) come first, which is where the negative Fibonacci
+// numbers show up with their alternating signs: F(-n) = (-1)^(n + 1) F(n)
+pub fn fibonacci_second_diff_sequence(len: usize) -> Vec<i128> {
+    fibonacci_diff_sequence(len + 1)
+        .windows(2)
+        .map(|pair| pair[1] as i128 - pair[0] as i128)
+        .collect()
+}
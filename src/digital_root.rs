@@ -0,0 +1,69 @@
+use crate::better_dynamic_fib;
+
+#[test]
+fn test_digital_root_period() {
+    let roots = digital_root_sequence(48);
+    assert_eq!(&roots[..8], &[0, 1, 1, 2, 3, 5, 8, 4]);
+    // F(0) is the only zero, after that it's exactly periodic
+    assert_eq!(roots[24], 9);
+    assert_eq!(&roots[1..24], &roots[25..48]);
+    assert_eq!(digital_root(186), 1);
+}
+
+#[test]
+fn test_fib_digit_sums() {
+    assert_eq!(
+        fib_digit_sums(19),
+        [0, 1, 1, 2, 3, 5, 8, 4, 3, 7, 10, 17, 9, 8, 17, 7, 24, 22, 19, 14]
+    );
+    assert_eq!(fib_digit_sum(20), 24);
+    assert_eq!(fib_digital_root_cycle_length(), 24);
+}
+
+// Compute F(n) and keep adding up its decimal digits until only one is
+// left. The digital roots of the Fibonacci numbers repeat with a period
+// of 24 (it's really the Pisano period for mod 9, with 9 standing in for
+// 0) except for F(0) itself which is the only one that is actually 0.
+// Only works up to n = 186 since it needs the real Fibonacci number
+pub fn digital_root(n: u128) -> u8 {
+    let mut value = better_dynamic_fib(n);
+    while value >= 10 {
+        value = digit_sum(value);
+    }
+    value as u8
+}
+
+fn digit_sum(mut value: u128) -> u128 {
+    let mut sum = 0;
+    while value > 0 {
+        sum += value % 10;
+        value /= 10;
+    }
+    sum
+}
+
+// The decimal digits of F(n) added up just once, so no more than 9 per
+// digit (F(186) has 39 of them)
+pub fn fib_digit_sum(n: u128) -> u128 {
+    digit_sum(better_dynamic_fib(n))
+}
+
+// fib_digit_sum for F(0) through F(n)
+pub fn fib_digit_sums(n: usize) -> Vec<u128> {
+    (0..=n as u128).map(fib_digit_sum).collect()
+}
+
+pub fn digital_root_sequence(len: usize) -> Vec<u8> {
+    (0..len as u128).map(digital_root).collect()
+}
+
+// The period of the digital roots, found by looking for the shortest
+// shift that lines every one of them up with itself again, instead of
+// trusting that it's 24. F(0) gets left out since it's the only 0
+pub fn fib_digital_root_cycle_length() -> usize {
+    let roots = digital_root_sequence(crate::MAX_FIB_INDEX_U128 as usize + 1);
+    let roots = &roots[1..];
+    (1..roots.len())
+        .find(|&period| roots.iter().zip(&roots[period..]).all(|(a, b)| a == b))
+        .unwrap()
+}
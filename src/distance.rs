@@ -0,0 +1,84 @@
+use crate::FIB_TABLE;
+
+#[test]
+fn test_nearest_fibonacci() {
+    assert_eq!(nearest_fibonacci(0), (0, 0));
+    assert_eq!(nearest_fibonacci(13), (13, 13));
+    assert_eq!(nearest_fibonacci(4), (3, 5));
+    assert_eq!(nearest_fibonacci(100), (89, 144));
+    let last = FIB_TABLE[crate::MAX_FIB_INDEX_U128 as usize];
+    assert_eq!(nearest_fibonacci(last + 1), (last, u128::MAX));
+}
+
+#[test]
+fn test_fibonacci_distance() {
+    assert_eq!(fibonacci_distance(0, 13), 1);
+    assert_eq!(fibonacci_distance(13, 0), 1);
+    assert_eq!(fibonacci_distance(7, 7), 0);
+    // 6 + 5 - 1 (or 6 + 3 + 1)
+    assert_eq!(fibonacci_distance(6, 10), 2);
+    assert_eq!(fibonacci_distance(0, u128::MAX), 36);
+
+    // a breadth first search over 0..LIMIT, allowed to step anywhere in
+    // there, always finds the same distance as always taking the nearest
+    const LIMIT: usize = 1000;
+    let steps: Vec<usize> = FIB_TABLE[1..]
+        .iter()
+        .map(|&fib| fib as usize)
+        .take_while(|&fib| fib < LIMIT)
+        .collect();
+    let mut distance = vec![None; LIMIT];
+    distance[0] = Some(0);
+    let mut queue = std::collections::VecDeque::from(vec![0]);
+    while let Some(gap) = queue.pop_front() {
+        for &step in &steps {
+            for next in [gap + step, gap.wrapping_sub(step)] {
+                if next < LIMIT && distance[next].is_none() {
+                    distance[next] = Some(distance[gap].unwrap() + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    for (gap, bfs) in distance.into_iter().enumerate() {
+        assert_eq!(
+            Some(fibonacci_distance(0, gap as u128)),
+            bfs,
+            "gap of {}",
+            gap
+        );
+    }
+}
+
+// The Fibonacci numbers on either side of n, (lower, upper), which are
+// both n when it is one. Past F(186) the upper one doesn't fit in a
+// u128, so that comes back as u128::MAX
+pub fn nearest_fibonacci(n: u128) -> (u128, u128) {
+    match FIB_TABLE.binary_search(&n) {
+        Ok(_) => (n, n),
+        Err(i) => (
+            FIB_TABLE[i - 1],
+            FIB_TABLE.get(i).copied().unwrap_or(u128::MAX),
+        ),
+    }
+}
+
+// How many times a Fibonacci number has to be added or subtracted to
+// get from a to b. Only the gap between them matters, and taking the
+// nearest Fibonacci number to whatever gap is left is always a shortest
+// way there (the test checks that against a breadth first search over
+// every step), so there's no need to search the whole integer graph
+pub fn fibonacci_distance(a: u128, b: u128) -> u128 {
+    let mut gap = a.max(b) - a.min(b);
+    let mut steps = 0;
+    while gap > 0 {
+        let (lower, upper) = nearest_fibonacci(gap);
+        gap = if upper != u128::MAX && upper - gap < gap - lower {
+            upper - gap
+        } else {
+            gap - lower
+        };
+        steps += 1;
+    }
+    steps
+}
@@ -0,0 +1,91 @@
+use crate::fibonacci_sum;
+use crate::iter::fibonacci_iter;
+
+#[test]
+fn test_fibonacci_entropy() {
+    assert_eq!(fibonacci_entropy(1), 0.0);
+    // 1/2 and 1/2
+    assert!((fibonacci_entropy(2) - 2f64.ln()).abs() < 1e-15);
+    assert!(fibonacci_entropy(186) > 0.0);
+    assert!((fibonacci_entropy(50) - fibonacci_entropy(51)).abs() < 1e-9);
+    assert!((fibonacci_entropy(50) - fibonacci_entropy(186)).abs() < 1e-9);
+    let phi = crate::convergence::PHI;
+    assert!((fibonacci_entropy(186) - (phi + 2.0) * phi.ln()).abs() < 1e-12);
+}
+
+#[test]
+fn test_sequence_entropy() {
+    // F(0) and F(1) are fifteen 0 bytes each, then a 0 and a 1
+    let (p0, p1) = (31.0 / 32.0, 1.0 / 32.0);
+    let expected = -p0 * f64::log2(p0) - p1 * f64::log2(p1);
+    assert!((sequence_entropy(1) - expected).abs() < 1e-12);
+    assert_eq!(sequence_entropy(0), 0.0);
+    assert!(sequence_entropy(186) <= 8.0);
+}
+
+#[test]
+fn test_compression_ratio_estimate() {
+    for n in 20..=186 {
+        let ratio = compression_ratio_estimate(n);
+        assert!(ratio > 0.0 && ratio < 1.0, "{} is {}", n, ratio);
+    }
+    assert_eq!(compression_ratio_estimate(0), 0.0);
+}
+
+// The biggest n fibonacci_entropy works for, F(1) through F(186)
+pub const MAX_ENTROPY_N: usize = 186;
+
+// Shannon entropy (in nats) of F(1) through F(n) as a probability
+// distribution, each one divided by their sum. The biggest few
+// Fibonacci numbers make up nearly all of the sum, and from the top down
+// each one is about 1/φ of the one above it (the biggest is about 1/φ^2
+// of the total), so it settles on the entropy of that geometric
+// distribution, (φ + 2) ln φ = 1.7410..., by about n = 50
+pub fn fibonacci_entropy(n: usize) -> f64 {
+    assert!(
+        (1..=MAX_ENTROPY_N).contains(&n),
+        "only F(1) through F(186) fit"
+    );
+    let fibs: Vec<f64> = fibonacci_iter()
+        .skip(1)
+        .take(n)
+        .map(|fib| fib as f64)
+        .collect();
+    // the sum stops fitting in a u128 after n = 184
+    let total = match fibonacci_sum(n as u128) {
+        Some(total) => total as f64,
+        None => fibs.iter().sum(),
+    };
+    fibs.iter()
+        .map(|&fib| fib / total)
+        .map(|p| -p * p.ln())
+        .sum()
+}
+
+// Shannon entropy (in bits per byte) of every byte of F(0) through F(n)
+// as big endian u128s. Most of those bytes are the leading 0s of numbers
+// that don't need all 16, which is where the entropy stays low
+pub fn sequence_entropy(n: usize) -> f64 {
+    assert!(n <= MAX_ENTROPY_N, "only F(0) through F(186) fit");
+    let mut counts = [0usize; 256];
+    for fib in fibonacci_iter().take(n + 1) {
+        for &byte in &fib.to_be_bytes() {
+            counts[byte as usize] += 1;
+        }
+    }
+    let total = (16 * (n + 1)) as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| count as f64 / total)
+        .map(|p| -p * p.log2())
+        .sum()
+}
+
+// About how small a compressor that only looks at how common each byte
+// is could get F(0) through F(n) as u128s, compared to the raw bytes:
+// the entropy's bits per byte out of the 8 each byte takes now. Real
+// compressors also pick up on repeats, so they can do better than this
+pub fn compression_ratio_estimate(n: usize) -> f64 {
+    sequence_entropy(n) / 8.0
+}
@@ -0,0 +1,178 @@
+use crate::algorithm::AlgorithmKind;
+use std::collections::HashMap;
+
+#[test]
+fn test_explain() {
+    let steps = explain(AlgorithmKind::BetterDynamic, 5);
+    for (step, pair) in ["(0, 1)", "(1, 1)", "(1, 2)", "(2, 3)", "(3, 5)"]
+        .iter()
+        .enumerate()
+    {
+        assert!(
+            steps.iter().any(|line| line.contains(&format!(
+                "step {}: (a, b) = {}",
+                step + 1,
+                pair
+            ))),
+            "missing step {}",
+            step + 1
+        );
+    }
+    // 15 calls, the 7 that recurse take two lines, plus the header and answer
+    assert_eq!(explain(AlgorithmKind::Backtrace, 5).len(), 24);
+    for &kind in &AlgorithmKind::ALL {
+        assert!(explain(kind, 5).last().unwrap().ends_with(" 5"));
+    }
+}
+
+// Nobody wants to read the call tree for anything bigger
+pub const MAX_EXPLAIN_N: u128 = 15;
+
+// A step by step, human readable account of how an algorithm gets to
+// F(n), one line per step. These walk through the same logic as the
+// real functions (which stay untouched so the race stays fair) rather
+// than instrumenting them
+pub fn explain(kind: AlgorithmKind, fib_num: u128) -> Vec<String> {
+    let mut lines = vec![format!("How {} solves fib:{}", kind.description(), fib_num)];
+    let answer = match kind {
+        AlgorithmKind::Backtrace => explain_backtrace(&mut lines, None, fib_num, 1),
+        AlgorithmKind::BacktraceMemo | AlgorithmKind::Cached => {
+            explain_backtrace(&mut lines, Some(&mut HashMap::new()), fib_num, 1)
+        }
+        AlgorithmKind::Dynamic => explain_dynamic(&mut lines, fib_num),
+        AlgorithmKind::BetterDynamic => explain_better_dynamic(&mut lines, fib_num),
+        AlgorithmKind::Adaptive => {
+            lines.push(format!(
+                "  {} is no more than {}, so F({}) fits in a u128 and it's better_dynamic_fib",
+                fib_num,
+                crate::MAX_FIB_INDEX_U128,
+                fib_num
+            ));
+            explain_better_dynamic(&mut lines, fib_num)
+        }
+        AlgorithmKind::Tailrec => explain_tailrec(&mut lines, fib_num),
+        AlgorithmKind::Lookup => {
+            lines.push(format!(
+                "  FIB_TABLE[{}] was already filled in by the compiler",
+                fib_num
+            ));
+            crate::lookup_fib(fib_num)
+        }
+    };
+    if kind == AlgorithmKind::Cached {
+        lines.push(
+            "  (the real cache sticks around between calls, so later rounds are one lookup)"
+                .to_string(),
+        );
+    }
+    lines.push(format!("  answer: {}", answer));
+    lines
+}
+
+// The call tree, indented by depth, with each call's answer on the
+// way back out. With a memo, repeat calls just get looked up
+fn explain_backtrace(
+    lines: &mut Vec<String>,
+    mut memo: Option<&mut HashMap<u128, u128>>,
+    fib_num: u128,
+    depth: usize,
+) -> u128 {
+    let indent = "  ".repeat(depth);
+    if let Some(&answer) = memo.as_ref().and_then(|memo| memo.get(&fib_num)) {
+        lines.push(format!(
+            "{}fib({}) is already in the memo: {}",
+            indent, fib_num, answer
+        ));
+        return answer;
+    }
+    if fib_num <= 1 {
+        lines.push(format!("{}fib({}) = {}", indent, fib_num, fib_num));
+        return fib_num;
+    }
+
+    lines.push(format!(
+        "{}fib({}) = fib({}) + fib({})",
+        indent,
+        fib_num,
+        fib_num - 1,
+        fib_num - 2
+    ));
+    let left = explain_backtrace(lines, memo.as_deref_mut(), fib_num - 1, depth + 1);
+    let right = explain_backtrace(lines, memo.as_deref_mut(), fib_num - 2, depth + 1);
+    let answer = left + right;
+    lines.push(format!(
+        "{}fib({}) = {} + {} = {}",
+        indent, fib_num, left, right, answer
+    ));
+    if let Some(memo) = memo {
+        memo.insert(fib_num, answer);
+    }
+    answer
+}
+
+fn explain_dynamic(lines: &mut Vec<String>, fib_num: u128) -> u128 {
+    let mut memo = vec![0, 1];
+    lines.push("  memo[0] = 0, memo[1] = 1".to_string());
+    for i in 2..=fib_num as usize {
+        memo.push(memo[i - 1] + memo[i - 2]);
+        lines.push(format!(
+            "  memo[{}] = memo[{}] + memo[{}] = {} + {} = {}",
+            i,
+            i - 1,
+            i - 2,
+            memo[i - 1],
+            memo[i - 2],
+            memo[i]
+        ));
+    }
+    memo[fib_num as usize]
+}
+
+// Step k is holding (F(k - 1), F(k)), step 1 being the (0, 1) it starts with
+fn explain_better_dynamic(lines: &mut Vec<String>, fib_num: u128) -> u128 {
+    let mut memo: (u128, u128) = (0, 1);
+    if fib_num == 0 {
+        lines.push("  fib(0) is just 0".to_string());
+        return 0;
+    }
+    lines.push(format!("  step 1: (a, b) = {:?} to start", memo));
+    for step in 2..=fib_num {
+        memo = (memo.1, memo.0 + memo.1);
+        lines.push(format!(
+            "  step {}: (a, b) = {:?}, the new b is the last a + b",
+            step, memo
+        ));
+    }
+    memo.1
+}
+
+fn explain_tailrec(lines: &mut Vec<String>, fib_num: u128) -> u128 {
+    let (mut n, mut a, mut b) = (fib_num, 0, 1);
+    loop {
+        match n {
+            0 => {
+                lines.push(format!("  tailrec_fib_inner(0, {}, {}) hands back a", a, b));
+                return a;
+            }
+            1 => {
+                lines.push(format!("  tailrec_fib_inner(1, {}, {}) hands back b", a, b));
+                return b;
+            }
+            _ => {
+                lines.push(format!(
+                    "  tailrec_fib_inner({}, {}, {}) calls tailrec_fib_inner({}, {}, {})",
+                    n,
+                    a,
+                    b,
+                    n - 1,
+                    b,
+                    a + b
+                ));
+                let sum = a + b;
+                a = b;
+                b = sum;
+                n -= 1;
+            }
+        }
+    }
+}
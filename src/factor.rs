@@ -0,0 +1,66 @@
+use crate::better_dynamic_fib;
+
+#[test]
+fn test_fibonacci_factors() {
+    assert_eq!(fibonacci_factors(1), []);
+    assert_eq!(fibonacci_factors(6), [(2, 3)]);
+    assert_eq!(fibonacci_factors(12), [(2, 4), (3, 2)]);
+    // F(p) for prime p is often prime too, but not always
+    assert_eq!(fibonacci_factors(11), [(89, 1)]);
+    assert_eq!(fibonacci_factors(19), [(37, 1), (113, 1)]);
+    for n in 1..=MAX_FACTOR_N {
+        let product: u128 = fibonacci_factors(n)
+            .iter()
+            .map(|&(prime, power)| prime.pow(power))
+            .product();
+        assert_eq!(product, better_dynamic_fib(n));
+    }
+    assert_eq!(format_factors(&fibonacci_factors(12)), "2^4 × 3^2");
+    assert_eq!(format_factors(&fibonacci_factors(11)), "89");
+}
+
+// F(30) = 832040 so trial division never has to go past 912
+pub const MAX_FACTOR_N: u128 = 30;
+
+// The prime factorization of F(n) as (prime, power) pairs, smallest
+// prime first, by trial division with the primes from primal. F(1) and
+// F(2) are 1, which has no prime factors at all
+pub fn fibonacci_factors(n: u128) -> Vec<(u128, u32)> {
+    assert!(
+        (1..=MAX_FACTOR_N).contains(&n),
+        "can only factor F(1) through F({})",
+        MAX_FACTOR_N
+    );
+    let mut remaining = better_dynamic_fib(n);
+    let mut factors = Vec::new();
+    for prime in primal::Primes::all().map(|prime| prime as u128) {
+        if prime * prime > remaining {
+            break;
+        }
+        let mut power = 0;
+        while remaining.is_multiple_of(prime) {
+            remaining /= prime;
+            power += 1;
+        }
+        if power > 0 {
+            factors.push((prime, power));
+        }
+    }
+    // whatever is left over has no factors below its square root
+    if remaining > 1 {
+        factors.push((remaining, 1));
+    }
+    factors
+}
+
+// Like 2^4 × 3^2, leaving off any ^1
+pub fn format_factors(factors: &[(u128, u32)]) -> String {
+    let parts: Vec<String> = factors
+        .iter()
+        .map(|&(prime, power)| match power {
+            1 => prime.to_string(),
+            _ => format!("{}^{}", prime, power),
+        })
+        .collect();
+    parts.join(" × ")
+}
@@ -0,0 +1,161 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::mem;
+
+#[test]
+fn test_fib_hash_map() {
+    let mut map = FibHashMap::new();
+    assert!(map.is_empty());
+    for key in 0..1000u64 {
+        assert_eq!(map.insert(key, key * 2), None);
+    }
+    assert_eq!(map.len(), 1000);
+    assert_eq!(map.insert(7, 0), Some(14));
+    assert_eq!(map.get(&7), Some(&0));
+    assert_eq!(map.get(&999), Some(&1998));
+    assert_eq!(map.get(&1000), None);
+
+    // removing leaves tombstones behind, lookups have to probe past them
+    for key in (0..1000).step_by(2) {
+        assert_eq!(map.remove(&key), Some(key * 2));
+    }
+    assert_eq!(map.len(), 500);
+    for key in 0..1000 {
+        assert_eq!(map.get(&key).is_some(), key % 2 == 1);
+    }
+    assert_eq!(map.remove(&0), None);
+}
+
+// 2^32 / φ, the 32 bit constant from Knuth's multiplicative hashing
+const FIB_STEP: u64 = 2_654_435_769;
+// and 2^64 / φ for picking the home slot out of the full 64 bit hash
+const FIB_HASH: u64 = 11_400_714_819_323_198_485;
+
+enum Slot<K, V> {
+    Empty,
+    Removed,
+    Full(K, V),
+}
+
+// Open addressing hash map where collisions probe by a Fibonacci hashed
+// step instead of just trying the next slot over. Multiplying by 2^n / φ
+// spreads neighboring keys far apart (φ being the "most irrational"
+// number), so keys that land together don't keep running into each
+// other the way they pile up into clusters with linear probing
+pub struct FibHashMap<K, V> {
+    slots: Vec<Slot<K, V>>,
+    len: usize,
+    removed: usize,
+}
+
+impl<K: Hash + Eq, V> Default for FibHashMap<K, V> {
+    fn default() -> Self {
+        FibHashMap::new()
+    }
+}
+
+impl<K: Hash + Eq, V> FibHashMap<K, V> {
+    pub fn new() -> FibHashMap<K, V> {
+        FibHashMap {
+            slots: (0..8).map(|_| Slot::Empty).collect(),
+            len: 0,
+            removed: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Every slot index the key could be in, in the order to try them.
+    // The table is always a power of 2 in size, so forcing the step to
+    // be odd means the probe eventually visits every slot
+    fn probe(&self, key: &K) -> impl Iterator<Item = usize> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let bits = self.slots.len().trailing_zeros();
+        let mask = self.slots.len() - 1;
+        let home = (hash.wrapping_mul(FIB_HASH) >> (64 - bits)) as usize;
+        let step = (hash.wrapping_mul(FIB_STEP) >> 32) as usize | 1;
+        (0..self.slots.len()).map(move |i| home.wrapping_add(i.wrapping_mul(step)) & mask)
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        for index in self.probe(key) {
+            match &self.slots[index] {
+                Slot::Empty => return None,
+                Slot::Full(k, _) if k == key => return Some(index),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match &self.slots[self.find(key)?] {
+            Slot::Full(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(index) = self.find(&key) {
+            if let Slot::Full(_, old) = &mut self.slots[index] {
+                return Some(mem::replace(old, value));
+            }
+        }
+
+        // tombstones count against the load too, they make probes longer
+        if (self.len + self.removed + 1) * 4 > self.slots.len() * 3 {
+            self.grow();
+        }
+        let index = self
+            .probe(&key)
+            .find(|&index| !matches!(self.slots[index], Slot::Full(..)))
+            .unwrap();
+        if let Slot::Removed = self.slots[index] {
+            self.removed -= 1;
+        }
+        self.slots[index] = Slot::Full(key, value);
+        self.len += 1;
+        None
+    }
+
+    // Can't just empty the slot, or probes for keys that collided with
+    // this one would stop early, so leave a tombstone to step over
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.find(key)?;
+        match mem::replace(&mut self.slots[index], Slot::Removed) {
+            Slot::Full(_, value) => {
+                self.len -= 1;
+                self.removed += 1;
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    // Double the table (unless it's mostly tombstones, then just clean
+    // up) and put everything back where it hashes to now
+    fn grow(&mut self) {
+        let size = if self.len * 2 > self.slots.len() {
+            self.slots.len() * 2
+        } else {
+            self.slots.len()
+        };
+        let old = mem::replace(&mut self.slots, (0..size).map(|_| Slot::Empty).collect());
+        self.len = 0;
+        self.removed = 0;
+        for slot in old {
+            if let Slot::Full(key, value) = slot {
+                self.insert(key, value);
+            }
+        }
+    }
+}
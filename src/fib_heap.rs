@@ -0,0 +1,284 @@
+use crate::better_dynamic_fib;
+use std::mem;
+
+#[test]
+fn test_fib_heap() {
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1987);
+    let mut heap = FibHeap::new();
+    let mut expected = Vec::new();
+    for round in 0..20 {
+        for _ in 0..100 {
+            let key = rng.gen_range(0..1000);
+            heap.insert(key);
+            expected.push(key);
+        }
+        expected.sort_unstable_by(|a, b| b.cmp(a));
+        for _ in 0..(round * 3) {
+            assert_eq!(heap.extract_min(), expected.pop());
+        }
+        assert_eq!(heap.find_min(), expected.last());
+        assert_eq!(heap.len(), expected.len());
+    }
+
+    let mut other = FibHeap::new();
+    for key in &[5000, -1, 42] {
+        other.insert(*key);
+        expected.push(*key);
+    }
+    heap.union(other);
+    expected.sort_unstable();
+    let mut drained = Vec::new();
+    while let Some(key) = heap.extract_min() {
+        drained.push(key);
+    }
+    assert_eq!(drained, expected);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_fib_heap_decrease_key() {
+    let mut heap = FibHeap::new();
+    let handles: Vec<FibHeapHandle> = (0..50).map(|key| heap.insert(key * 10)).collect();
+    // pull one out so the rest get consolidated into real trees
+    assert_eq!(heap.extract_min(), Some(0));
+    heap.decrease_key(handles[30], 5);
+    heap.decrease_key(handles[49], -1);
+    heap.decrease_key(handles[10], 4);
+    assert_eq!(heap.extract_min(), Some(-1));
+    assert_eq!(heap.extract_min(), Some(4));
+    assert_eq!(heap.extract_min(), Some(5));
+    assert_eq!(heap.extract_min(), Some(10));
+    assert_eq!(heap.len(), 45);
+}
+
+// Where a key lives in the heap, so it can be found again for
+// decrease_key. Stops meaning anything once that key is extracted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FibHeapHandle(usize);
+
+struct Node<T> {
+    key: Option<T>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    marked: bool,
+}
+
+// Fredman and Tarjan's Fibonacci heap. Inserting and merging just toss
+// trees onto the root list and all the tidying up is put off until
+// extract_min, which links trees of the same degree together. Decrease
+// key cuts nodes loose, and a node that has lost two children gets cut
+// too (the mark), which keeps every node of degree k sitting on top of
+// at least F(k + 2) nodes. That's where the name comes from, and why no
+// node can have more than log_φ(n) children.
+//
+// The nodes live in one Vec and point at each other by index (slots
+// freed by extract_min get reused) to keep the borrow checker happy
+pub struct FibHeap<T: Ord> {
+    nodes: Vec<Node<T>>,
+    roots: Vec<usize>,
+    min: Option<usize>,
+    len: usize,
+    free: Vec<usize>,
+}
+
+impl<T: Ord> Default for FibHeap<T> {
+    fn default() -> Self {
+        FibHeap::new()
+    }
+}
+
+impl<T: Ord> FibHeap<T> {
+    pub fn new() -> FibHeap<T> {
+        FibHeap {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+            min: None,
+            len: 0,
+            free: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn key(&self, index: usize) -> &T {
+        self.nodes[index]
+            .key
+            .as_ref()
+            .expect("that key isn't in the heap any more")
+    }
+
+    fn update_min(&mut self, index: usize) {
+        if self.min.is_none_or(|min| self.key(index) < self.key(min)) {
+            self.min = Some(index);
+        }
+    }
+
+    pub fn insert(&mut self, key: T) -> FibHeapHandle {
+        let node = Node {
+            key: Some(key),
+            parent: None,
+            children: Vec::new(),
+            marked: false,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        self.roots.push(index);
+        self.update_min(index);
+        self.len += 1;
+        FibHeapHandle(index)
+    }
+
+    pub fn find_min(&self) -> Option<&T> {
+        self.min.map(|min| self.key(min))
+    }
+
+    // Everything in other moves over here, its handles are no good after
+    pub fn union(&mut self, other: FibHeap<T>) {
+        let offset = self.nodes.len();
+        for mut node in other.nodes {
+            node.parent = node.parent.map(|parent| parent + offset);
+            for child in node.children.iter_mut() {
+                *child += offset;
+            }
+            self.nodes.push(node);
+        }
+        self.free
+            .extend(other.free.into_iter().map(|index| index + offset));
+        self.len += other.len;
+        for root in other.roots {
+            self.roots.push(root + offset);
+            self.update_min(root + offset);
+        }
+    }
+
+    pub fn extract_min(&mut self) -> Option<T> {
+        let min = self.min.take()?;
+        let position = self.roots.iter().position(|&root| root == min).unwrap();
+        self.roots.swap_remove(position);
+        for child in mem::take(&mut self.nodes[min].children) {
+            self.nodes[child].parent = None;
+            self.nodes[child].marked = false;
+            self.roots.push(child);
+        }
+
+        let key = self.nodes[min].key.take();
+        self.free.push(min);
+        self.len -= 1;
+        self.consolidate();
+        key
+    }
+
+    // Link roots of the same degree until every root has a different
+    // degree, then find the new min among what's left
+    fn consolidate(&mut self) {
+        let mut by_degree: Vec<Option<usize>> = Vec::new();
+        for root in mem::take(&mut self.roots) {
+            let mut tree = root;
+            loop {
+                let degree = self.nodes[tree].children.len();
+                if by_degree.len() <= degree {
+                    by_degree.resize(degree + 1, None);
+                }
+                match by_degree[degree].take() {
+                    Some(other) => tree = self.link(tree, other),
+                    None => {
+                        by_degree[degree] = Some(tree);
+                        break;
+                    }
+                }
+            }
+        }
+
+        for root in by_degree.into_iter().flatten() {
+            self.roots.push(root);
+            self.update_min(root);
+        }
+        debug_assert!(self.degree_bound_holds());
+    }
+
+    // The bigger key becomes a child of the smaller one
+    fn link(&mut self, a: usize, b: usize) -> usize {
+        let (parent, child) = if self.key(b) < self.key(a) {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.nodes[child].parent = Some(parent);
+        self.nodes[child].marked = false;
+        self.nodes[parent].children.push(child);
+        parent
+    }
+
+    pub fn decrease_key(&mut self, handle: FibHeapHandle, key: T) {
+        let index = handle.0;
+        assert!(
+            key <= *self.key(index),
+            "decrease_key can't make a key bigger"
+        );
+        self.nodes[index].key = Some(key);
+
+        if let Some(parent) = self.nodes[index].parent {
+            if self.key(index) < self.key(parent) {
+                self.cut(index, parent);
+                self.cascading_cut(parent);
+            }
+        }
+        self.update_min(index);
+    }
+
+    fn cut(&mut self, child: usize, parent: usize) {
+        self.nodes[parent].children.retain(|&other| other != child);
+        self.nodes[child].parent = None;
+        self.nodes[child].marked = false;
+        self.roots.push(child);
+    }
+
+    // First lost child just marks the parent, the second one cuts it
+    // loose too (and so on up the tree)
+    fn cascading_cut(&mut self, index: usize) {
+        if let Some(parent) = self.nodes[index].parent {
+            if self.nodes[index].marked {
+                self.cut(index, parent);
+                self.cascading_cut(parent);
+            } else {
+                self.nodes[index].marked = true;
+            }
+        }
+    }
+
+    fn subtree_size(&self, index: usize) -> u128 {
+        1 + self.nodes[index]
+            .children
+            .iter()
+            .map(|&child| self.subtree_size(child))
+            .sum::<u128>()
+    }
+
+    // Every node of degree k has to have at least F(k + 2) nodes under
+    // it (counting itself), only checked in debug builds since it walks
+    // the whole heap
+    fn degree_bound_holds(&self) -> bool {
+        (0..self.nodes.len())
+            .filter(|&index| self.nodes[index].key.is_some())
+            .all(|index| {
+                let degree = self.nodes[index].children.len() as u128;
+                self.subtree_size(index) >= better_dynamic_fib(degree + 2)
+            })
+    }
+}
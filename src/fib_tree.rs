@@ -0,0 +1,152 @@
+use std::fmt::Display;
+
+#[test]
+fn test_fib_tree() {
+    let tree = FibTree::build(6);
+    assert_eq!(tree.node_count(), 25);
+    assert_eq!(tree.depth(), 6);
+    assert_eq!(FibTree::build(0).node_count(), 1);
+    assert_eq!(FibTree::<u128>(None).depth(), 0);
+
+    let dot = FibTree::build(2).to_dot();
+    assert_eq!(
+        dot,
+        "digraph fib_tree {\n  n0 [label=\"2\"];\n  n0 -> n1;\n  n1 [label=\"1\"];\n  n0 -> n2;\n  n2 [label=\"0\"];\n}\n"
+    );
+}
+
+#[test]
+fn test_fibonacci_tree_sort() {
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(186);
+    for len in &[0, 1, 2, 10, 1000] {
+        let mut data: Vec<u32> = (0..*len).map(|_| rng.gen_range(0..100)).collect();
+        let mut expected = data.clone();
+        expected.sort();
+        fibonacci_tree_sort(&mut data);
+        assert_eq!(data, expected);
+    }
+}
+
+// The call tree that backtrace_fib walks, built out for real so it can
+// be measured (and drawn). Each node is one call, with the n - 1 and
+// n - 2 calls it makes as its children
+// (the keys can be anything though, so it doubles as a plain binary
+// search tree for fibonacci_tree_sort)
+pub struct FibTree<T = u128>(pub Option<Box<FibNode<T>>>);
+
+pub struct FibNode<T = u128> {
+    pub key: T,
+    pub left: FibTree<T>,
+    pub right: FibTree<T>,
+}
+
+impl FibTree {
+    pub fn build(fib_num: u128) -> FibTree {
+        let (left, right) = if fib_num <= 1 {
+            (FibTree(None), FibTree(None))
+        } else {
+            (FibTree::build(fib_num - 1), FibTree::build(fib_num - 2))
+        };
+        FibTree(Some(Box::new(FibNode {
+            key: fib_num,
+            left,
+            right,
+        })))
+    }
+}
+
+impl<T> FibTree<T> {
+    pub fn depth(&self) -> usize {
+        match &self.0 {
+            Some(node) => 1 + node.left.depth().max(node.right.depth()),
+            None => 0,
+        }
+    }
+
+    // Same as the number of calls backtrace_fib makes, 2 * F(n + 1) - 1
+    pub fn node_count(&self) -> usize {
+        match &self.0 {
+            Some(node) => 1 + node.left.node_count() + node.right.node_count(),
+            None => 0,
+        }
+    }
+
+    // Walks down the left side stacking nodes up, then pops them off in
+    // order. Each node gets taken apart on the way out so dropping the
+    // tree never has to recurse (sorted input makes a very deep tree)
+    fn into_sorted(self, sorted: &mut Vec<T>) {
+        let mut stack = Vec::new();
+        let mut current = self.0;
+        loop {
+            while let Some(mut node) = current {
+                current = node.left.0.take();
+                stack.push(node);
+            }
+            match stack.pop() {
+                Some(mut node) => {
+                    current = node.right.0.take();
+                    sorted.push(node.key);
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+impl<T: Ord> FibTree<T> {
+    // Plain BST insert, ties go to the right so equal keys stay in order
+    pub fn insert(&mut self, key: T) {
+        let mut current = &mut self.0;
+        while let Some(node) = current {
+            current = if key < node.key {
+                &mut node.left.0
+            } else {
+                &mut node.right.0
+            };
+        }
+        *current = Some(Box::new(FibNode {
+            key,
+            left: FibTree(None),
+            right: FibTree(None),
+        }));
+    }
+}
+
+impl<T: Display> FibTree<T> {
+    // GraphViz wants every node to have a unique id, but the keys repeat
+    // all over the place so number them as we go instead
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph fib_tree {\n");
+        let mut next_id = 0;
+        self.write_dot(&mut dot, &mut next_id);
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String, next_id: &mut usize) {
+        if let Some(node) = &self.0 {
+            let id = *next_id;
+            *next_id += 1;
+            dot.push_str(&format!("  n{} [label=\"{}\"];\n", id, node.key));
+            for child in &[&node.left, &node.right] {
+                if child.0.is_some() {
+                    dot.push_str(&format!("  n{} -> n{};\n", id, next_id));
+                    child.write_dot(dot, next_id);
+                }
+            }
+        }
+    }
+}
+
+// Toss everything into a tree and read it back out in order. That's
+// O(n log n) for shuffled data, but sorted data turns the tree into one
+// long (O(n²)) list, so this is really just for fun
+pub fn fibonacci_tree_sort<T: Ord + Clone>(data: &mut Vec<T>) {
+    let mut tree = FibTree(None);
+    for item in data.drain(..) {
+        tree.insert(item);
+    }
+    tree.into_sorted(data);
+}
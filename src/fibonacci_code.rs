@@ -0,0 +1,118 @@
+use bitvec::prelude::*;
+
+#[test]
+fn test_fibonacci_code() {
+    let expected = ["11", "011", "0011", "1011", "00011", "10011", "01011"];
+    for (number, code) in (1..=7).zip(expected.iter()) {
+        assert_eq!(bits_to_string(&encode_sequence(&[number])), *code);
+    }
+
+    let numbers = [1, 2, 3, 5, 8, 13, 21, 34, 4, 1000, u64::MAX];
+    assert_eq!(
+        decode_sequence(&encode_sequence(&numbers)).as_deref(),
+        Some(&numbers[..])
+    );
+    assert_eq!(decode_sequence(&BitVec::new()).as_deref(), Some(&[][..]));
+    assert!(compression_ratio(&numbers[..8]) < 1.0);
+}
+
+#[test]
+fn test_decode_malformed() {
+    // a code with no 11 on the end
+    let mut bits = encode_sequence(&[5, 8]);
+    bits.extend_from_bitslice(bits![0, 1, 0]);
+    assert_eq!(decode_sequence(&bits), None);
+    // more digits than a u64 has Fibonacci numbers for
+    assert_eq!(decode_sequence(&bitvec![0; 200]), None);
+    // every other digit through F(93) adds up to more than a u64 holds
+    let mut bits: BitVec = (0..92).map(|position| position % 2 == 1).collect();
+    bits.extend_from_bitslice(bits![1]);
+    assert_eq!(decode_sequence(&bits), None);
+}
+
+// The "digits" of a Fibonacci code are F(2), F(3), F(4), ... so we
+// skip the duplicate 1 and keep going until the next one won't fit
+// in a u64
+fn code_digits() -> Vec<u64> {
+    let mut digits = vec![1u64, 2];
+    while let Some(next) = digits[digits.len() - 1].checked_add(digits[digits.len() - 2]) {
+        digits.push(next);
+    }
+    digits
+}
+
+// Greedily take the biggest Fibonacci number that still fits (which is
+// just the Zeckendorf representation, so never two 1s in a row), write
+// the bits smallest digit first and then tack on an extra 1. That final
+// "11" can't appear anywhere else, so the codes are prefix-free and can
+// be concatenated without any separators
+fn encode_into(bits: &mut BitVec, digits: &[u64], number: u64) {
+    assert!(
+        number > 0,
+        "Fibonacci coding only works for positive integers"
+    );
+
+    let mut code = bitvec![0; digits.iter().take_while(|&&digit| digit <= number).count()];
+    let mut remainder = number;
+    for (position, &digit) in digits.iter().enumerate().take(code.len()).rev() {
+        if digit <= remainder {
+            code.set(position, true);
+            remainder -= digit;
+        }
+    }
+    bits.extend_from_bitslice(&code);
+    bits.push(true);
+}
+
+pub fn encode_sequence(numbers: &[u64]) -> BitVec {
+    let digits = code_digits();
+    let mut bits = BitVec::new();
+    for &number in numbers {
+        encode_into(&mut bits, &digits, number);
+    }
+    bits
+}
+
+// Add up digits until we see two 1s in a row, which ends the number.
+// None if the bits can't have come from encode_sequence: a code longer
+// than any u64's, one that adds up to more than a u64, or leftover bits
+// at the end with no 11 to finish them
+pub fn decode_sequence(bits: &BitVec) -> Option<Vec<u64>> {
+    let digits = code_digits();
+    let mut numbers = Vec::new();
+    let (mut number, mut position, mut last_bit) = (0u64, 0, false);
+    for bit in bits.iter().by_vals() {
+        if bit && last_bit {
+            numbers.push(number);
+            number = 0;
+            position = 0;
+            last_bit = false;
+            continue;
+        }
+        if bit {
+            number = number.checked_add(*digits.get(position)?)?;
+        } else if position >= digits.len() {
+            return None;
+        }
+        position += 1;
+        last_bit = bit;
+    }
+    if position > 0 {
+        return None;
+    }
+    Some(numbers)
+}
+
+// How big the Fibonacci coded bits are compared to just storing every
+// number as a plain u64 (so smaller is better)
+pub fn compression_ratio(numbers: &[u64]) -> f64 {
+    let raw_bits = numbers.len() * u64::BITS as usize;
+    encode_sequence(numbers).len() as f64 / raw_bits as f64
+}
+
+pub fn bits_to_string(bits: &BitSlice) -> String {
+    bits.iter()
+        .by_vals()
+        .map(|bit| if bit { '1' } else { '0' })
+        .collect()
+}
@@ -0,0 +1,81 @@
+use crate::better_dynamic_fib;
+
+#[test]
+fn test_fib_gray() {
+    for n in 0..=100 {
+        assert_eq!(fib_gray_decode(fib_gray_encode(n)), n);
+    }
+    for n in (0..1_000).chain(MAX_FIB_GRAY - 1_000..MAX_FIB_GRAY) {
+        let (code, next) = (fib_gray_encode(n), fib_gray_encode(n + 1));
+        assert_eq!((code ^ next).count_ones(), 1, "{} and {}", n, n + 1);
+        assert_eq!(code & (code >> 1), 0, "{} has two 1s in a row", n);
+    }
+    assert_eq!(fib_gray_decode(fib_gray_encode(MAX_FIB_GRAY)), MAX_FIB_GRAY);
+}
+
+// Every code is this many Fibonacci digits, bit i standing for F(i + 2)
+// the same as in Fibonacci coding
+pub const FIB_GRAY_WIDTH: u32 = 128;
+
+// How many numbers fit, F(130) - 1 being the last
+pub const MAX_FIB_GRAY: u128 = 659_034_621_587_630_041_982_498_214;
+
+// How many strings of width digits there are with no two 1s in a row,
+// which is F(width + 2)
+fn count(width: u32) -> u128 {
+    better_dynamic_fib(width as u128 + 2)
+}
+
+// The binary reflected Gray code lists the n digit strings as a 0 and
+// then each n - 1 digit string, followed by a 1 and each of them again
+// backwards, so the two halves meet where only the new digit changes.
+// Here the strings are Zeckendorf ones (no two 1s in a row), so after a
+// 1 there has to be a 0: the list is a 0 and the width - 1 list
+// backwards, then a 10 and the width - 2 list backwards. Reversing both
+// halves is what makes them meet with a single digit flipping, and it
+// means the list doesn't start at 0 the way binary's does, so every code
+// is the full FIB_GRAY_WIDTH digits wide rather than only as long as n
+// needs. fib_gray_encode(n) is the nth string in that list
+pub fn fib_gray_encode(n: u128) -> u128 {
+    assert!(
+        n <= MAX_FIB_GRAY,
+        "only 0 to {} have a Fibonacci Gray code",
+        MAX_FIB_GRAY
+    );
+    encode(FIB_GRAY_WIDTH, n)
+}
+
+fn encode(width: u32, n: u128) -> u128 {
+    match width {
+        0 => 0,
+        1 => n,
+        _ if n < count(width - 1) => encode(width - 1, count(width - 1) - 1 - n),
+        _ => {
+            let n = n - count(width - 1);
+            (1 << (width - 1)) | encode(width - 2, count(width - 2) - 1 - n)
+        }
+    }
+}
+
+// Where code is in the list, reading the digits from the top down
+pub fn fib_gray_decode(code: u128) -> u128 {
+    assert_eq!(
+        code & (code >> 1),
+        0,
+        "{:b} has two 1s in a row, so it isn't a Fibonacci Gray code",
+        code
+    );
+    decode(FIB_GRAY_WIDTH, code)
+}
+
+fn decode(width: u32, code: u128) -> u128 {
+    match width {
+        0 => 0,
+        1 => code,
+        _ if code >> (width - 1) == 0 => count(width - 1) - 1 - decode(width - 1, code),
+        _ => {
+            let rest = code & !(1 << (width - 1));
+            count(width - 1) + count(width - 2) - 1 - decode(width - 2, rest)
+        }
+    }
+}
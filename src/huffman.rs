@@ -0,0 +1,183 @@
+use crate::better_dynamic_fib;
+use crate::fibonacci_code::{bits_to_string, encode_sequence};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+#[test]
+fn test_fib_huffman_code() {
+    let codes = fib_huffman_code(&[('a', 0.5), ('b', 0.25), ('c', 0.125), ('d', 0.125)]);
+    let mut lengths: Vec<(char, usize)> = codes.iter().map(|(&c, code)| (c, code.len())).collect();
+    lengths.sort_unstable();
+    assert_eq!(lengths, [('a', 1), ('b', 2), ('c', 3), ('d', 3)]);
+    assert!(is_prefix_free(&codes));
+
+    assert_eq!(fib_huffman_code(&[('x', 1.0)])[&'x'], "0");
+    assert!(fib_huffman_code(&[]).is_empty());
+}
+
+#[test]
+fn test_fib_optimal_lengths() {
+    assert_eq!(fib_optimal_lengths(0), []);
+    assert_eq!(fib_optimal_lengths(1), [1]);
+    assert_eq!(fib_optimal_lengths(2), [1, 1]);
+    assert_eq!(fib_optimal_lengths(6), [1, 2, 3, 4, 5, 5]);
+
+    // Fibonacci weights really do build the most lopsided tree there is
+    for n_symbols in 2..=20 {
+        let symbols = fibonacci_weights(n_symbols);
+        let codes = fib_huffman_code(&symbols);
+        let lengths: Vec<usize> = symbols.iter().map(|(c, _)| codes[c].len()).collect();
+        assert_eq!(lengths, fib_optimal_lengths(n_symbols));
+        assert!(is_prefix_free(&codes));
+    }
+}
+
+#[test]
+fn test_huffman_against_fibonacci_code() {
+    let english = [
+        ('e', 0.127),
+        ('t', 0.091),
+        ('a', 0.082),
+        ('o', 0.075),
+        ('i', 0.070),
+        ('n', 0.067),
+        ('s', 0.063),
+        ('h', 0.061),
+        ('r', 0.060),
+        ('d', 0.043),
+    ];
+    let uniform: Vec<(char, f64)> = ('a'..='p').map(|c| (c, 1.0 / 16.0)).collect();
+    for symbols in &[english.to_vec(), uniform, fibonacci_weights(12)] {
+        let total: f64 = symbols.iter().map(|(_, p)| p).sum();
+        let entropy: f64 = symbols
+            .iter()
+            .map(|(_, p)| -(p / total) * (p / total).log2())
+            .sum();
+        let huffman = average_code_length(symbols, &fib_huffman_code(symbols));
+        let fibonacci = average_code_length(symbols, &fibonacci_codes(symbols));
+        // no prefix code beats the entropy, and Huffman's is the best
+        // prefix code there is, Fibonacci coding included
+        assert!(entropy <= huffman + 1e-9 && huffman < entropy + 1.0);
+        assert!(huffman <= fibonacci);
+    }
+}
+
+// A node's total probability, ordered so the heap can pop the smallest.
+// Ties go to whichever node was made first, so the same symbols always
+// get the same codes
+struct Weight(f64, usize);
+
+impl PartialEq for Weight {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Weight {}
+
+impl PartialOrd for Weight {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Weight {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0).then(self.1.cmp(&other.1))
+    }
+}
+
+// Keep merging the two least likely nodes until there's just the root,
+// then read each symbol's code off the path down to it (0 for left, 1
+// for right). There's no tree to walk if there's only one symbol, so it
+// just gets "0"
+pub fn fib_huffman_code(symbols: &[(char, f64)]) -> HashMap<char, String> {
+    // children of every merged node, which come after the leaves
+    let mut children: Vec<(usize, usize)> = Vec::new();
+    let mut heap: BinaryHeap<Reverse<Weight>> = symbols
+        .iter()
+        .enumerate()
+        .map(|(leaf, &(_, probability))| Reverse(Weight(probability, leaf)))
+        .collect();
+    while heap.len() > 1 {
+        let Reverse(Weight(left_p, left)) = heap.pop().unwrap();
+        let Reverse(Weight(right_p, right)) = heap.pop().unwrap();
+        children.push((left, right));
+        heap.push(Reverse(Weight(
+            left_p + right_p,
+            symbols.len() + children.len() - 1,
+        )));
+    }
+
+    let mut codes = HashMap::new();
+    let mut stack = match heap.pop() {
+        Some(Reverse(Weight(_, root))) if root < symbols.len() => vec![(root, "0".to_string())],
+        Some(Reverse(Weight(_, root))) => vec![(root, String::new())],
+        None => Vec::new(),
+    };
+    while let Some((node, code)) = stack.pop() {
+        if node < symbols.len() {
+            codes.insert(symbols[node].0, code);
+        } else {
+            let (left, right) = children[node - symbols.len()];
+            stack.push((left, format!("{}0", code)));
+            stack.push((right, format!("{}1", code)));
+        }
+    }
+    codes
+}
+
+// The code lengths Huffman hands out when the probabilities go like
+// F(n), F(n - 1), ..., F(1). Every merge is the newest node with the
+// next leaf up (since F(1) + ... + F(k) = F(k + 2) - 1 stays just under
+// the leaf after next), so the tree is as deep as it can be: 1, 2, 3,
+// ... with the last two symbols sharing the bottom level
+pub fn fib_optimal_lengths(n_symbols: usize) -> Vec<usize> {
+    match n_symbols {
+        0 => Vec::new(),
+        1 => vec![1],
+        _ => (1..n_symbols).chain(Some(n_symbols - 1)).collect(),
+    }
+}
+
+// n_symbols letters from 'a' on, weighted F(n_symbols) down to F(1)
+pub fn fibonacci_weights(n_symbols: usize) -> Vec<(char, f64)> {
+    (1..=n_symbols as u128)
+        .rev()
+        .zip('a'..)
+        .map(|(k, c)| (c, better_dynamic_fib(k) as f64))
+        .collect()
+}
+
+// Whether no code is the start of another one, so a stream of them can
+// be split back up without anything in between
+pub fn is_prefix_free(codes: &HashMap<char, String>) -> bool {
+    codes.iter().all(|(a, code_a)| {
+        codes
+            .iter()
+            .all(|(b, code_b)| a == b || !code_b.starts_with(code_a.as_str()))
+    })
+}
+
+// Bits per symbol on average, weighting each code by how likely its
+// symbol is (the probabilities don't have to add up to 1)
+pub fn average_code_length(symbols: &[(char, f64)], codes: &HashMap<char, String>) -> f64 {
+    let total: f64 = symbols.iter().map(|(_, probability)| probability).sum();
+    symbols
+        .iter()
+        .map(|(c, probability)| probability * codes[c].len() as f64)
+        .sum::<f64>()
+        / total
+}
+
+// Fibonacci coding as a prefix code to measure Huffman against: the most
+// likely symbol gets the code for 1, the next the code for 2, and so on
+pub fn fibonacci_codes(symbols: &[(char, f64)]) -> HashMap<char, String> {
+    let mut ranked: Vec<&(char, f64)> = symbols.iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+        .iter()
+        .zip(1..)
+        .map(|(&&(c, _), rank)| (c, bits_to_string(&encode_sequence(&[rank]))))
+        .collect()
+}
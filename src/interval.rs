@@ -0,0 +1,155 @@
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+#[test]
+fn test_fib_interval_ops() {
+    let a = FibInterval::new(1.0, 2.0);
+    let b = FibInterval::new(-3.0, 0.5);
+    let sum = a + b;
+    assert!(sum.lo <= -2.0 && sum.hi >= 2.5);
+    let product = a * b;
+    assert!(product.lo <= -6.0 && product.hi >= 1.0);
+    assert!(product.lo > -6.0001 && product.hi < 1.0001);
+    let difference = a - b;
+    assert!(difference.lo <= 0.5 && difference.hi >= 5.0);
+    assert!(FibInterval::exact(3.0).contains(3));
+    assert_eq!(FibInterval::new(1.5, 2.5).to_string(), "[1.5, 2.5]");
+}
+
+#[test]
+fn test_binet_fib_interval() {
+    for n in 0..=186 {
+        let interval = binet_fib_interval(n);
+        assert!(interval.contains(crate::lookup_fib(n as u128)), "F({})", n);
+        assert_eq!(
+            interval.pins_down(),
+            (n as u128) < FIRST_LOOSE_BINET_INTERVAL,
+            "F({}) is in {}",
+            n,
+            interval
+        );
+    }
+}
+
+// There's no n where binet_fib_interval loses F(n), that's the point of
+// rounding outwards (and test_binet_fib_interval checks all the way to
+// F(186)). What happens instead is it gets too wide to be any use: from
+// F(66) on there's more than one integer in it, so it can't say which
+// one F(n) is. Plain f64 Binet, rounded to the nearest integer, is
+// still right until F(76), it just has no way of knowing it is
+pub const FIRST_LOOSE_BINET_INTERVAL: u128 = 66;
+
+// Somewhere between lo and hi is the real number. Every operation rounds
+// lo down and hi up by one step (f64 has no way to ask for the rounding
+// direction), so whatever the float error, the real answer is still in
+// between, the interval just gets wider as the errors pile up
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FibInterval {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+impl FibInterval {
+    pub fn new(lo: f64, hi: f64) -> FibInterval {
+        assert!(lo <= hi, "[{}, {}] is backwards", lo, hi);
+        FibInterval { lo, hi }
+    }
+
+    // Only for values f64 holds exactly, like small integers
+    pub fn exact(value: f64) -> FibInterval {
+        FibInterval::new(value, value)
+    }
+
+    // Rounded out a step at each end, for a result that may have been
+    // rounded either way
+    fn widened(lo: f64, hi: f64) -> FibInterval {
+        FibInterval::new(lo.next_down(), hi.next_up())
+    }
+
+    pub fn width(self) -> f64 {
+        self.hi - self.lo
+    }
+
+    // Whether the integer is in there, compared exactly (as f64, a big
+    // integer could round to the wrong side of lo or hi)
+    pub fn contains(self, value: u128) -> bool {
+        self.lo.floor() as u128 <= value && value <= self.hi.ceil() as u128
+    }
+
+    // Whether there's only one integer it could be
+    pub fn pins_down(self) -> bool {
+        self.hi.floor() - self.lo.ceil() < 1.0
+    }
+
+    fn recip(self) -> FibInterval {
+        assert!(self.lo > 0.0 || self.hi < 0.0, "{} has 0 in it", self);
+        FibInterval::widened(1.0 / self.hi, 1.0 / self.lo)
+    }
+
+    fn powi(self, mut exp: u32) -> FibInterval {
+        let mut result = FibInterval::exact(1.0);
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl Add for FibInterval {
+    type Output = FibInterval;
+
+    fn add(self, other: FibInterval) -> FibInterval {
+        FibInterval::widened(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+
+impl Sub for FibInterval {
+    type Output = FibInterval;
+
+    fn sub(self, other: FibInterval) -> FibInterval {
+        FibInterval::widened(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+
+// With signs in the mix the smallest and biggest products could come
+// from any pair of ends
+impl Mul for FibInterval {
+    type Output = FibInterval;
+
+    fn mul(self, other: FibInterval) -> FibInterval {
+        let products = [
+            self.lo * other.lo,
+            self.lo * other.hi,
+            self.hi * other.lo,
+            self.hi * other.hi,
+        ];
+        FibInterval::widened(
+            products.iter().cloned().fold(f64::INFINITY, f64::min),
+            products.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        )
+    }
+}
+
+impl fmt::Display for FibInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+// Binet's formula, F(n) = (φ^n - ψ^n) / √5 with φ and ψ = (1 ± √5) / 2,
+// worked out with intervals. sqrt is correctly rounded, so √5 is within
+// a step of 5f64.sqrt() and everything after that is interval arithmetic
+pub fn binet_fib_interval(n: u32) -> FibInterval {
+    let root = 5f64.sqrt();
+    let sqrt5 = FibInterval::widened(root, root);
+    let one = FibInterval::exact(1.0);
+    let half = FibInterval::exact(0.5);
+    let phi = (one + sqrt5) * half;
+    let psi = (one - sqrt5) * half;
+    (phi.powi(n) - psi.powi(n)) * sqrt5.recip()
+}
@@ -0,0 +1,323 @@
+use crate::automaton::FibAutomaton;
+use crate::convergence::PHI;
+use crate::{better_dynamic_fib, MAX_FIB_INDEX_U128};
+use std::convert::TryFrom;
+
+#[test]
+fn test_fib_iter() {
+    let first: Vec<u128> = fibonacci_iter().take(10).collect();
+    assert_eq!(first, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    assert_eq!(fibonacci_iter().count(), 187);
+    assert_eq!(
+        fibonacci_iter().last(),
+        Some(crate::better_dynamic_fib(186))
+    );
+}
+
+#[test]
+fn test_fib_iter_from() {
+    assert_eq!(fibonacci_iter_from(20).next(), Some(6765));
+    assert!(fibonacci_iter_from(20).eq(fibonacci_iter().skip(20)));
+    assert!(fibonacci_iter_from(0).eq(fibonacci_iter()));
+    assert_eq!(fibonacci_iter_from(186).count(), 1);
+    assert_eq!(fibonacci_iter_from(187).next(), None);
+    assert_eq!(fibonacci_iter_from(u128::MAX).next(), None);
+}
+
+#[test]
+fn test_fib_pair_iter() {
+    let pairs: Vec<(u128, u128)> = fibonacci_pair_iter().take(4).collect();
+    assert_eq!(pairs, [(0, 1), (1, 1), (1, 2), (2, 3)]);
+    assert_eq!(fibonacci_pair_iter().count(), 186);
+    assert_eq!(
+        fibonacci_pair_iter().last(),
+        Some((better_dynamic_fib(185), better_dynamic_fib(186)))
+    );
+    // F(n + 1) - F(n) is F(n - 1)
+    let differences: Vec<u128> = fibonacci_pair_iter()
+        .skip(1)
+        .take(9)
+        .map(|(a, b)| b - a)
+        .collect();
+    assert_eq!(differences, fibonacci_iter().take(9).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_consecutive_ratios() {
+    let ratios: Vec<(f64, f64)> = fibonacci_pair_iter().consecutive_ratios().collect();
+    assert_eq!(ratios.len(), 185);
+    assert_eq!(ratios[0], (1.0, crate::convergence::PHI - 1.0));
+    assert_eq!(ratios[2].0, 1.5);
+    assert!(ratios.iter().skip(40).all(|&(_, error)| error < 1e-15));
+}
+
+#[test]
+fn test_lucas_iter() {
+    let first: Vec<u128> = lucas_iter().take(10).collect();
+    assert_eq!(first, [2, 1, 3, 4, 7, 11, 18, 29, 47, 76]);
+    // L(n) = F(n - 1) + F(n + 1)
+    for (n, lucas) in lucas_iter().enumerate().skip(1).take(180) {
+        let n = n as u128;
+        assert_eq!(lucas, better_dynamic_fib(n - 1) + better_dynamic_fib(n + 1));
+    }
+}
+
+#[test]
+fn test_interleave_fibonacci() {
+    let pairs: Vec<(u128, u128)> = interleave_fibonacci().skip(10).take(3).collect();
+    assert_eq!(pairs, [(55, 5), (89, 9), (144, 4)]);
+    assert!(interleave_fibonacci().all(|(fib, digit)| fib % 10 == digit));
+    assert_eq!(interleave_fibonacci().count(), 187);
+}
+
+#[test]
+fn test_merge_fibonacci_sequences() {
+    // L(0) = 2 comes before L(1) = 1, so the Lucas numbers are only
+    // sorted from L(1) on
+    let mut merged: Vec<u128> =
+        merge_fibonacci_sequences(fibonacci_iter(), lucas_iter().skip(1)).collect();
+    assert_eq!(merged.len(), 187 + 184);
+    assert!(merged.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(&merged[..10], &[0, 1, 1, 1, 2, 3, 3, 4, 5, 7]);
+    merged.dedup();
+    assert!(merged.windows(2).all(|pair| pair[0] < pair[1]));
+    // 1 (three times over) and 3 are the only numbers in both
+    assert_eq!(merged.len(), 187 + 184 - 3);
+}
+
+#[test]
+fn test_step_by_fib() {
+    let stepped: Vec<u64> = (0u64..).step_by_fib(5).take(5).collect();
+    assert_eq!(stepped, [0, 5, 10, 15, 20]);
+    let stepped: Vec<char> = "abcdefghijklmnopqrstuvwxyz"
+        .chars()
+        .step_by_fib(6)
+        .collect();
+    assert_eq!(stepped, ['a', 'i', 'q', 'y']);
+    assert_eq!((0..5).step_by_fib(1).count(), 5);
+}
+
+#[test]
+fn test_fibonacci_filter() {
+    let primes: Vec<u128> = fibonacci_filter(crate::prime::is_probably_prime)
+        .take(8)
+        .collect();
+    assert_eq!(primes, [2, 3, 5, 13, 89, 233, 1597, 28657]);
+    assert_eq!(fibonacci_filter(|_| false).count(), 0);
+}
+
+#[test]
+fn test_fibonacci_map() {
+    let strings: Vec<String> = fibonacci_map(|fib| fib.to_string())
+        .skip(5)
+        .take(4)
+        .collect();
+    assert_eq!(strings, ["5", "8", "13", "21"]);
+    assert_eq!(fibonacci_map(|fib| fib).count(), 187);
+}
+
+#[test]
+fn test_fibonacci_scan() {
+    let sums: Vec<u128> = fibonacci_scan(0, |sum, fib| (sum + fib, sum + fib))
+        .take(10)
+        .collect();
+    assert_eq!(sums, [0, 1, 2, 4, 7, 12, 20, 33, 54, 88]);
+    // the sum up to F(n) is F(n + 2) - 1
+    for (n, sum) in sums.iter().enumerate() {
+        assert_eq!(*sum, better_dynamic_fib(n as u128 + 2) - 1);
+    }
+    // the state doesn't have to be what comes out
+    let evens: Vec<(usize, u128)> = fibonacci_scan(0, |&count, fib| (count + 1, (count, fib % 2)))
+        .take(6)
+        .collect();
+    assert_eq!(evens, [(0, 0), (1, 1), (2, 1), (3, 0), (4, 1), (5, 1)]);
+}
+
+// F(0), F(1), F(2)... for as long as they fit in a u128 (so through
+// F(186)), using the same keep-only-the-last-two idea as
+// better_dynamic_fib
+pub struct FibIter {
+    current: Option<u128>,
+    next: Option<u128>,
+}
+
+impl FibIter {
+    pub fn new() -> FibIter {
+        FibIter {
+            current: Some(0),
+            next: Some(1),
+        }
+    }
+}
+
+impl Default for FibIter {
+    fn default() -> Self {
+        FibIter::new()
+    }
+}
+
+impl Iterator for FibIter {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        let current = self.current?;
+        self.current = self.next;
+        self.next = self.next.and_then(|next| next.checked_add(current));
+        Some(current)
+    }
+}
+
+pub fn fibonacci_iter() -> FibIter {
+    FibIter::new()
+}
+
+// FibIter, but starting at F(start_n) instead of F(0). The two values
+// it starts holding are worked out directly instead of iterating up to
+// them
+pub struct FibIterFrom(FibIter);
+
+impl Iterator for FibIterFrom {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        self.0.next()
+    }
+}
+
+pub fn fibonacci_iter_from(start_n: u128) -> FibIterFrom {
+    let fits = |n| n <= MAX_FIB_INDEX_U128;
+    FibIterFrom(FibIter {
+        current: Some(start_n).filter(|&n| fits(n)).map(better_dynamic_fib),
+        next: start_n
+            .checked_add(1)
+            .filter(|&n| fits(n))
+            .map(better_dynamic_fib),
+    })
+}
+
+// (F(0), F(1)), (F(1), F(2))... through (F(185), F(186)), for when
+// each one is needed along with the one after it
+pub struct FibPairIter {
+    pair: Option<(u128, u128)>,
+}
+
+impl Iterator for FibPairIter {
+    type Item = (u128, u128);
+
+    fn next(&mut self) -> Option<(u128, u128)> {
+        let (a, b) = self.pair?;
+        self.pair = a.checked_add(b).map(|next| (b, next));
+        Some((a, b))
+    }
+}
+
+impl FibPairIter {
+    // F(n + 1) / F(n) for each pair, and how far that is from φ. The
+    // (0, 1) pair is skipped since there's no ratio for it
+    pub fn consecutive_ratios(self) -> impl Iterator<Item = (f64, f64)> {
+        self.filter(|&(a, _)| a > 0).map(|(a, b)| {
+            let ratio = b as f64 / a as f64;
+            (ratio, (ratio - PHI).abs())
+        })
+    }
+}
+
+pub fn fibonacci_pair_iter() -> FibPairIter {
+    FibPairIter { pair: Some((0, 1)) }
+}
+
+// The Lucas numbers, L(0) = 2, L(1) = 1, and then the same adding as
+// the Fibonacci numbers (so FibIter does all the work), through L(184)
+pub fn lucas_iter() -> FibIter {
+    FibIter {
+        current: Some(2),
+        next: Some(1),
+    }
+}
+
+// (F(n), F(n) mod 10), the second half coming from an automaton
+// running mod 10 alongside instead of from F(n) itself
+pub fn interleave_fibonacci() -> impl Iterator<Item = (u128, u128)> {
+    fibonacci_iter().zip(FibAutomaton::new(10))
+}
+
+// Two sorted sequences merged into one sorted sequence, taking the
+// smaller front value each time like the merge in merge sort. Values in
+// both come out twice
+pub fn merge_fibonacci_sequences(
+    seq1: impl Iterator<Item = u128>,
+    seq2: impl Iterator<Item = u128>,
+) -> impl Iterator<Item = u128> {
+    let mut seq1 = seq1.peekable();
+    let mut seq2 = seq2.peekable();
+    std::iter::from_fn(move || match (seq1.peek(), seq2.peek()) {
+        (Some(a), Some(b)) if a <= b => seq1.next(),
+        (Some(_), Some(_)) => seq2.next(),
+        (Some(_), None) => seq1.next(),
+        (None, _) => seq2.next(),
+    })
+}
+
+// Just the Fibonacci numbers predicate likes
+pub fn fibonacci_filter<F: Fn(u128) -> bool>(predicate: F) -> impl Iterator<Item = u128> {
+    fibonacci_iter().filter(move |&fib| predicate(fib))
+}
+
+// f of each Fibonacci number instead of the number itself
+pub fn fibonacci_map<B, F: Fn(u128) -> B>(f: F) -> impl Iterator<Item = B> {
+    fibonacci_iter().map(f)
+}
+
+// Carries state along the sequence: f gets the state so far and the
+// next Fibonacci number, and hands back the new state and what to yield
+pub fn fibonacci_scan<S, B, F: Fn(&S, u128) -> (S, B)>(init: S, f: F) -> impl Iterator<Item = B> {
+    fibonacci_iter().scan(init, move |state, fib| {
+        let (next, item) = f(state, fib);
+        *state = next;
+        Some(item)
+    })
+}
+
+// Scan until overflow to find the last F(n) that fits, the runtime twin
+// of MAX_FIB_INDEX_U128
+pub fn max_fib_index() -> u128 {
+    fibonacci_iter().count() as u128 - 1
+}
+
+// Like step_by, but the step is F(k): hands back the first element and
+// then every F(k)th one after that
+pub struct StepByFib<I> {
+    iter: I,
+    step: usize,
+    counter: usize,
+}
+
+impl<I: Iterator> Iterator for StepByFib<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let item = self.iter.next()?;
+            let keep = self.counter == 0;
+            self.counter = (self.counter + 1) % self.step;
+            if keep {
+                return Some(item);
+            }
+        }
+    }
+}
+
+pub trait IterFibExt: Iterator + Sized {
+    fn step_by_fib(self, k: usize) -> StepByFib<Self> {
+        let step =
+            usize::try_from(better_dynamic_fib(k as u128)).expect("F(k) is too big to step by");
+        assert!(step > 0, "F(0) is 0 and you can't step by 0");
+        StepByFib {
+            iter: self,
+            step,
+            counter: 0,
+        }
+    }
+}
+
+impl<I: Iterator> IterFibExt for I {}
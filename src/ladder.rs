@@ -0,0 +1,111 @@
+use crate::iter::fibonacci_iter;
+
+#[test]
+fn test_fib_ladder_pow() {
+    assert_eq!(fib_ladder_pow(2, 10, 1000), 24);
+    assert_eq!(fib_ladder_pow(7, 0, 13), 1);
+    assert_eq!(fib_ladder_pow(7, 5, 1), 0);
+    for base in 0..20 {
+        for exp in 0..200 {
+            assert_eq!(fib_ladder_pow(base, exp, 1009), binary_pow(base, exp, 1009));
+        }
+    }
+    // a modulus too big to just multiply two remainders together
+    let big = u128::MAX - 158;
+    assert_eq!(
+        fib_ladder_pow(3, u128::MAX, big),
+        binary_pow(3, u128::MAX, big)
+    );
+    assert_eq!(binary_pow(2, 127, big), 1 << 127);
+}
+
+#[test]
+fn test_fib_ladder_pow_timing() {
+    use std::time::Instant;
+
+    let modulus = (1 << 61) - 1;
+    let now = Instant::now();
+    let ladder: u128 = (0..2000)
+        .map(|exp| fib_ladder_pow(3, exp * 997, modulus))
+        .sum();
+    let ladder_time = now.elapsed();
+    let now = Instant::now();
+    let binary: u128 = (0..2000).map(|exp| binary_pow(3, exp * 997, modulus)).sum();
+    let binary_time = now.elapsed();
+    assert_eq!(ladder, binary);
+    // not a race anyone expects the ladder to win, just a sanity check
+    // that it isn't wildly off (timings vary too much to be strict)
+    assert!(ladder_time < binary_time * 100);
+}
+
+// a * b % modulus without overflowing, by doubling and adding when the
+// product might not fit
+fn mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if modulus <= 1 << 64 {
+        return a * b % modulus;
+    }
+    let (mut a, mut b, mut product) = (a, b, 0u128);
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+    product
+}
+
+fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+// base^exp mod modulus with a Fibonacci addition chain: each power
+// base^F(k) is just the product of the two before it (since F(k) is
+// F(k - 1) + F(k - 2)), and exp is a sum of Fibonacci numbers
+// (Zeckendorf's theorem), so multiply together the powers for the
+// Fibonacci numbers in exp. That takes about log_φ(exp) steps up the
+// ladder instead of the log_2(exp) squarings of binary_pow, so it's
+// simple but a bit slower
+pub fn fib_ladder_pow(base: u128, exp: u128, modulus: u128) -> u128 {
+    assert!(modulus > 0, "can't work mod 0");
+    let base = base % modulus;
+    // F(2) = 1, F(3) = 2, ... up through the biggest one in exp, with
+    // base to the power of each one
+    let mut rungs: Vec<(u128, u128)> = Vec::new();
+    for fib in fibonacci_iter().skip(2).take_while(|&fib| fib <= exp) {
+        let power = match rungs.len() {
+            0 => base,
+            1 => mul_mod(base, base, modulus),
+            len => mul_mod(rungs[len - 1].1, rungs[len - 2].1, modulus),
+        };
+        rungs.push((fib, power));
+    }
+
+    let mut result = 1 % modulus;
+    let mut remainder = exp;
+    for &(fib, power) in rungs.iter().rev() {
+        if fib <= remainder {
+            result = mul_mod(result, power, modulus);
+            remainder -= fib;
+        }
+    }
+    result
+}
+
+// The usual square and multiply, one bit of exp at a time
+pub fn binary_pow(base: u128, exp: u128, modulus: u128) -> u128 {
+    assert!(modulus > 0, "can't work mod 0");
+    let (mut base, mut exp, mut result) = (base % modulus, exp, 1 % modulus);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
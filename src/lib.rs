@@ -0,0 +1,492 @@
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+use cached::proc_macro::cached;
+use cached::Cached;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub mod affinity;
+pub mod algorithm;
+pub mod arena;
+pub mod audio;
+pub mod automaton;
+pub mod backoff;
+pub mod bandwidth;
+pub mod base_phi;
+pub mod bigint;
+pub mod bitset;
+pub mod bst;
+pub mod cache;
+pub mod checksum;
+pub mod cipher;
+pub mod clock;
+pub mod compositions;
+#[cfg(feature = "constant-time")]
+pub mod constant_time;
+pub mod convergence;
+pub mod datagen;
+pub mod diff;
+pub mod digital_root;
+pub mod distance;
+pub mod entropy;
+pub mod explain;
+pub mod factor;
+pub mod fib_hash_map;
+pub mod fib_heap;
+pub mod fib_tree;
+pub mod fibonacci_code;
+pub mod gray;
+pub mod huffman;
+pub mod interval;
+pub mod iter;
+pub mod ladder;
+pub mod lsystem;
+pub mod markdown;
+pub mod morse;
+pub mod numerals;
+pub mod oeis;
+pub mod palette;
+pub mod parity;
+pub mod plugin;
+pub mod polynomial;
+pub mod predict;
+pub mod prime;
+pub mod qr;
+pub mod radix;
+pub mod rate_limiter;
+pub mod recurrence;
+pub mod report;
+pub mod roman;
+pub mod scan;
+pub mod search;
+pub mod sieve;
+#[cfg(feature = "simd")]
+pub mod simd;
+pub mod skip_list;
+pub mod spiral;
+pub mod stats;
+pub mod text;
+pub mod trie;
+pub mod units;
+pub mod validate;
+pub mod zeckendorf;
+
+#[test]
+fn test_each_version() {
+    assert_eq!(backtrace_fib(20), 6765);
+    assert_eq!(backtrace_memo_fib(&mut HashMap::new(), 20), 6765);
+    assert_eq!(dynamic_fib(20), 6765);
+    assert_eq!(better_dynamic_fib(20), 6765);
+    assert_eq!(cached_fib(20), 6765);
+    assert_eq!(tailrec_fib(20), 6765);
+    assert_eq!(lookup_fib(20), 6765);
+}
+
+#[test]
+fn test_lookup_fib() {
+    for fib_num in 0..=MAX_FIB_INDEX_U128 {
+        assert_eq!(lookup_fib(fib_num), better_dynamic_fib(fib_num));
+    }
+}
+
+#[test]
+fn test_is_fibonacci() {
+    let fibs: Vec<u128> = (0..=100).filter(|&value| is_fibonacci(value)).collect();
+    assert_eq!(fibs, [0, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]);
+    assert!(is_fibonacci(lookup_fib(MAX_FIB_INDEX_U128)));
+    assert!(!is_fibonacci(u128::MAX));
+}
+
+#[test]
+fn test_signed_fib() {
+    let negafibs: Vec<i128> = (-10..=-1).rev().map(signed_fib).collect();
+    assert_eq!(negafibs, [1, -1, 2, -3, 5, -8, 13, -21, 34, -55]);
+    for fib_num in 0..=MAX_SIGNED_FIB_INDEX {
+        assert_eq!(
+            signed_fib(fib_num) as u128,
+            better_dynamic_fib(fib_num as u128)
+        );
+    }
+    // F(n) = F(n - 1) + F(n - 2) on both sides of 0
+    for fib_num in -MAX_SIGNED_FIB_INDEX + 2..=MAX_SIGNED_FIB_INDEX {
+        assert_eq!(
+            signed_fib(fib_num),
+            signed_fib(fib_num - 1) + signed_fib(fib_num - 2)
+        );
+    }
+}
+
+#[test]
+fn test_fibonacci_sum() {
+    for n in 0..=30 {
+        let sum: u128 = (1..=n).map(better_dynamic_fib).sum();
+        assert_eq!(fibonacci_sum(n), Some(sum));
+    }
+    assert!(fibonacci_sum(184).is_some());
+    assert_eq!(fibonacci_sum(185), None);
+}
+
+#[test]
+fn test_tailrec_fib() {
+    for fib_num in 0..=30 {
+        assert_eq!(tailrec_fib(fib_num), backtrace_fib(fib_num));
+    }
+    assert_eq!(tailrec_fib(186), better_dynamic_fib(186));
+}
+
+#[test]
+fn test_cached_fib_entries() {
+    cached_fib(20);
+    let entries = cached_fib_entries();
+    for &(fib_num, answer) in &[(0, 0), (1, 1), (19, 4181), (20, 6765)] {
+        assert!(
+            entries.contains(&(fib_num, answer)),
+            "F({}) isn't cached",
+            fib_num
+        );
+    }
+    assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    let (hits, misses) = cached_fib_stats();
+    assert!(hits > 0 && misses > 0);
+}
+
+#[test]
+fn test_call_count() {
+    take_call_count();
+    backtrace_fib(10);
+    assert_eq!(take_call_count(), 177);
+    backtrace_memo_fib(&mut HashMap::new(), 10);
+    assert_eq!(take_call_count(), 19);
+}
+
+#[test]
+fn test_num_recursive_calls() {
+    for fib_num in 0..=20 {
+        take_call_count();
+        backtrace_fib(fib_num);
+        assert_eq!(
+            take_call_count() as u128,
+            num_recursive_calls_backtrace(fib_num)
+        );
+        backtrace_memo_fib(&mut HashMap::new(), fib_num);
+        assert_eq!(take_call_count() as u128, num_recursive_calls_memo(fib_num));
+    }
+}
+
+#[test]
+fn test_num_recursive_calls_limit() {
+    let calls = num_recursive_calls_backtrace(MAX_CALL_FORMULA_N);
+    assert_eq!(calls, 2 * better_dynamic_fib(184) - 1);
+    assert!(better_dynamic_fib(185).checked_mul(2).is_none());
+}
+
+#[test]
+#[should_panic(expected = "doesn't fit in a u128")]
+fn test_num_recursive_calls_past_limit() {
+    num_recursive_calls_backtrace(MAX_CALL_FORMULA_N + 1);
+}
+
+#[test]
+fn test_max_fib_index() {
+    assert_eq!(MAX_FIB_INDEX_U128, 186);
+    assert_eq!(iter::max_fib_index(), MAX_FIB_INDEX_U128);
+    assert!(better_dynamic_fib(MAX_FIB_INDEX_U128) < u128::MAX);
+}
+
+#[test]
+fn test_max_fib_index_overflows() {
+    let last = better_dynamic_fib(MAX_FIB_INDEX_U128);
+    let second_last = better_dynamic_fib(MAX_FIB_INDEX_U128 - 1);
+    assert_eq!(second_last.checked_add(last), None);
+}
+
+#[test]
+fn test_fib_macro() {
+    let runtime_n = 20;
+    assert_eq!(fib!(0), 0);
+    assert_eq!(fib!(1), 1);
+    assert_eq!(fib!(runtime_n), 6765);
+    assert_eq!(
+        fib!(186),
+        332_825_110_087_067_562_321_196_029_789_634_457_848
+    );
+}
+
+// The biggest n where F(n) still fits in a u128, worked out by the
+// compiler by adding until it overflows instead of just trusting that
+// it's 186
+pub const MAX_FIB_INDEX_U128: u128 = max_fib_index_u128();
+
+const fn max_fib_index_u128() -> u128 {
+    let mut memo: (u128, u128) = (0, 1);
+    let mut fib_num = 1;
+    loop {
+        match memo.0.checked_add(memo.1) {
+            Some(next) => memo = (memo.1, next),
+            None => return fib_num,
+        }
+        fib_num += 1;
+    }
+}
+
+// How many times the recursive versions have called themselves, kept
+// per thread so tests running in parallel don't count each other
+thread_local! {
+    static CALL_COUNT: AtomicU64 = const { AtomicU64::new(0) };
+}
+
+fn count_call() {
+    CALL_COUNT.with(|count| count.fetch_add(1, Ordering::Relaxed));
+}
+
+// Hands back the calls made since the last time this was called
+pub fn take_call_count() -> u64 {
+    CALL_COUNT.with(|count| count.swap(0, Ordering::Relaxed))
+}
+
+// The biggest n num_recursive_calls_backtrace can count for, since
+// 2 F(185) is already past u128::MAX
+pub const MAX_CALL_FORMULA_N: u128 = 183;
+
+// How many calls backtrace_fib(n) makes, without making them. Calls(n)
+// is 1 + Calls(n - 1) + Calls(n - 2), and Calls(0) = Calls(1) = 1, which
+// works out to 2 F(n + 1) - 1. Only good up to MAX_CALL_FORMULA_N, past
+// that twice F(n + 1) is too big for a u128
+pub fn num_recursive_calls_backtrace(fib_num: u128) -> u128 {
+    assert!(
+        fib_num <= MAX_CALL_FORMULA_N,
+        "2 F({}) - 1 doesn't fit in a u128",
+        fib_num + 1
+    );
+    2 * better_dynamic_fib(fib_num + 1) - 1
+}
+
+// With the memo each n from 2 on gets worked out once, calling n - 1
+// (worked out) and n - 2 (just looked up), so that's 2 calls per n plus
+// one for the 1 at the very bottom
+pub fn num_recursive_calls_memo(fib_num: u128) -> u128 {
+    match fib_num {
+        0 => 1,
+        _ => 2 * fib_num - 1,
+    }
+}
+
+// Simple recursion to backtrace our way backwards down the chain
+// to 2 (which gets fixed answers for 0 and 1) and then unwinds to
+// get the answer
+pub fn backtrace_fib(fib_num: u128) -> u128 {
+    count_call();
+    if fib_num == 0 || fib_num == 1 {
+        return fib_num;
+    }
+    backtrace_fib(fib_num - 1) + backtrace_fib(fib_num - 2)
+}
+
+// Simliar to above, but brings in a HashMap for memoization (weird
+// that this is the only way to keep the memo around for future calls)
+// otherwise, works the same as the backtrace before, it is justs faster
+// because of the memoization
+pub fn backtrace_memo_fib(memo: &mut HashMap<u128, u128>, fib_num: u128) -> u128 {
+    count_call();
+    match memo.get(&fib_num).copied() {
+        Some(result) => result,
+        None => {
+            let result = match fib_num {
+                0 | 1 => fib_num,
+                n => backtrace_memo_fib(memo, n - 1) + backtrace_memo_fib(memo, n - 2),
+            };
+            memo.insert(fib_num, result);
+            result
+        }
+    }
+}
+
+// Loop thru the chain purposely instead of relying on recursion
+// This code is less idiomatic of Rust though, uses an overly complex
+// HashMap and keeps the entire chain in memory (see next method)
+pub fn dynamic_fib(fib_num: u128) -> u128 {
+    let mut memo = HashMap::new();
+    memo.insert(0, 0);
+    memo.insert(1, 1);
+    match fib_num {
+        0 | 1 => {} // already set
+        n => {
+            for i in 2..=n {
+                let result = *memo.get(&(i - 1)).unwrap() + *memo.get(&(i - 2)).unwrap();
+                memo.insert(i, result);
+            }
+        }
+    };
+    *memo.get(&fib_num).unwrap()
+}
+
+// Similar idea as above, but uses a tuple instead of a HashMap (duh)
+// and constructs the chain of Fib numbers keeping ONLY the last 2
+// as it works it's way to the fib_num
+pub fn better_dynamic_fib(fib_num: u128) -> u128 {
+    let mut memo = (0, 1);
+
+    match fib_num {
+        0 | 1 => fib_num,
+        _ => {
+            for _ in 2..=fib_num {
+                memo = (memo.1, memo.0 + memo.1)
+            }
+            memo.1
+        }
+    }
+}
+
+// F(1) + F(2) + ... + F(n) without adding them all up, since it always
+// comes to F(n + 2) - 1. None once F(n + 2) is past F(186)
+pub fn fibonacci_sum(n: u128) -> Option<u128> {
+    let after_next = n.checked_add(2).filter(|&n| n <= MAX_FIB_INDEX_U128)?;
+    Some(lookup_fib(after_next) - 1)
+}
+
+// F(185) is too big for an i128, so this is as far as signed_fib goes
+// in either direction
+pub const MAX_SIGNED_FIB_INDEX: i64 = 184;
+
+// The sequence run backwards past 0 too (the negafibonacci numbers,
+// 1, -1, 2, -3, 5...), since F(n - 2) = F(n) - F(n - 1) works just as
+// well as adding. Going down only flips the sign every other step, so
+// F(-n) = (-1)^(n + 1) F(n): the size comes from the usual tuple swap
+// and the sign is kept track of separately (negative for even -n)
+pub fn signed_fib(fib_num: i64) -> i128 {
+    assert!(
+        fib_num.abs() <= MAX_SIGNED_FIB_INDEX,
+        "F({}) doesn't fit in an i128",
+        fib_num
+    );
+    let mut memo: (i128, i128) = (0, 1);
+    let size = match fib_num.abs() {
+        n @ 0..=1 => n as i128,
+        n => {
+            for _ in 2..=n {
+                memo = (memo.1, memo.0 + memo.1)
+            }
+            memo.1
+        }
+    };
+    let negative = fib_num < 0 && fib_num % 2 == 0;
+    if negative {
+        -size
+    } else {
+        size
+    }
+}
+
+// Recursion again, but carrying the last two answers along as we go
+// (like the tuple above) so the recursive call is the very last thing
+// that happens. Languages that guarantee tail call optimization turn
+// that into a loop, but Rust doesn't promise it (destructors that have
+// to run after the call, and getting it right on every platform, make it
+// hard), so this only avoids the deep stack when LLVM decides to do it
+// for us, which in practice it does in --release
+pub fn tailrec_fib(fib_num: u128) -> u128 {
+    fn tailrec_fib_inner(fib_num: u128, a: u128, b: u128) -> u128 {
+        match fib_num {
+            0 => a,
+            1 => b,
+            n => tailrec_fib_inner(n - 1, b, a + b),
+        }
+    }
+    tailrec_fib_inner(fib_num, 0, 1)
+}
+
+// cached crate memoizes input->output of this function for us so
+// we don't have to do any of it. We just do a simple recursive
+// backtrace and everything is sped up because of memoization in
+// the background
+#[cached(size = 200)]
+pub fn cached_fib(fib_num: u128) -> u128 {
+    if fib_num == 0 || fib_num == 1 {
+        return fib_num;
+    }
+    cached_fib(fib_num - 1) + cached_fib(fib_num - 2)
+}
+
+// Everything cached_fib has remembered so far, as (n, F(n)) sorted by n
+pub fn cached_fib_entries() -> Vec<(u128, u128)> {
+    let cache = CACHED_FIB.lock().unwrap();
+    let mut entries: Vec<(u128, u128)> = cache
+        .key_order()
+        .copied()
+        .zip(cache.value_order().copied())
+        .collect();
+    entries.sort_unstable();
+    entries
+}
+
+// (hits, misses) for cached_fib since the program started
+pub fn cached_fib_stats() -> (u64, u64) {
+    let cache = CACHED_FIB.lock().unwrap();
+    (
+        cache.cache_hits().unwrap_or(0),
+        cache.cache_misses().unwrap_or(0),
+    )
+}
+
+// Same tuple trick as better_dynamic_fib, but written with a while loop
+// so the compiler is happy to run it for a const
+pub const fn const_fib(fib_num: u128) -> u128 {
+    if fib_num < 2 {
+        return fib_num;
+    }
+    let mut memo = (0, 1);
+    let mut i = 2;
+    while i <= fib_num {
+        memo = (memo.1, memo.0 + memo.1);
+        i += 1;
+    }
+    memo.1
+}
+
+// Every Fibonacci Number that fits in a u128, all worked out by the
+// compiler, so there's nothing left to do at runtime but index into it
+pub const FIB_TABLE: [u128; MAX_FIB_INDEX_U128 as usize + 1] = fib_table();
+
+const fn fib_table() -> [u128; MAX_FIB_INDEX_U128 as usize + 1] {
+    let mut table = [0; MAX_FIB_INDEX_U128 as usize + 1];
+    table[1] = 1;
+    let mut i = 2;
+    while i < table.len() {
+        table[i] = table[i - 1] + table[i - 2];
+        i += 1;
+    }
+    table
+}
+
+pub fn lookup_fib(fib_num: u128) -> u128 {
+    FIB_TABLE[fib_num as usize]
+}
+
+// Whether value shows up anywhere in the sequence (FIB_TABLE is sorted,
+// the two 1s at the start don't get in the way of a binary search)
+pub fn is_fibonacci(value: u128) -> bool {
+    FIB_TABLE.binary_search(&value).is_ok()
+}
+
+/// Fibonacci Number n as a `u128`, worked out at compile time when n is
+/// a literal and by `better_dynamic_fib` when it is only known at runtime
+///
+/// ```
+/// use fibonacci_race::fib;
+///
+/// let x: u128 = fib!(20); // expands to a const, so 6765u128
+/// assert_eq!(x, 6765);
+///
+/// let n = 20;
+/// assert_eq!(fib!(n), 6765);
+/// ```
+#[macro_export]
+macro_rules! fib {
+    ($fib_num:literal) => {{
+        const FIB: u128 = $crate::const_fib($fib_num);
+        FIB
+    }};
+    ($fib_num:expr) => {
+        $crate::better_dynamic_fib($fib_num)
+    };
+}
@@ -0,0 +1,33 @@
+#[test]
+fn test_fibonacci_lsystem() {
+    let generations: Vec<String> = (0..5).map(fibonacci_lsystem).collect();
+    assert_eq!(generations, ["A", "AB", "ABA", "ABAAB", "ABAABABA"]);
+    for n in 0..=15 {
+        assert_eq!(
+            fibonacci_lsystem(n).len(),
+            crate::better_dynamic_fib(n as u128 + 2) as usize
+        );
+    }
+}
+
+// Generation 30 is already F(32) = 2,178,309 symbols long
+pub const MAX_LSYSTEM_GENERATIONS: usize = 30;
+
+// Lindenmayer's model of algae growing: every A (grown up) becomes AB
+// and every B (a baby) becomes A, all at once each generation. The
+// grown ups never go away and the babies grow up one generation later,
+// which is the rabbit puzzle Fibonacci started with, so generation n
+// is F(n + 2) symbols long (and it's also the nth Fibonacci word)
+pub fn fibonacci_lsystem(generations: usize) -> String {
+    let mut symbols = String::from("A");
+    for _ in 0..generations {
+        symbols = symbols
+            .chars()
+            .map(|symbol| match symbol {
+                'A' => "AB",
+                _ => "A",
+            })
+            .collect();
+    }
+    symbols
+}
@@ -1,169 +1,1855 @@
-use cached::proc_macro::cached;
-use std::collections::HashMap;
+use fibonacci_race::affinity;
+use fibonacci_race::algorithm::{self, AlgorithmKind, AlgorithmResult};
+use fibonacci_race::audio;
+use fibonacci_race::automaton::{FibAutomaton, MAX_AUTOMATON_MODULUS};
+use fibonacci_race::backoff::FibBackoff;
+use fibonacci_race::bandwidth;
+use fibonacci_race::base_phi;
+use fibonacci_race::bigint;
+use fibonacci_race::checksum;
+use fibonacci_race::clock;
+use fibonacci_race::compositions;
+use fibonacci_race::convergence;
+use fibonacci_race::datagen;
+use fibonacci_race::diff::{self, MAX_DIFF_TABLE_N};
+use fibonacci_race::digital_root;
+use fibonacci_race::distance;
+use fibonacci_race::entropy::{self, MAX_ENTROPY_N};
+use fibonacci_race::explain::{self, MAX_EXPLAIN_N};
+use fibonacci_race::factor;
+use fibonacci_race::fibonacci_code;
+use fibonacci_race::gray;
+use fibonacci_race::interval;
+use fibonacci_race::iter::{fibonacci_iter, max_fib_index};
+use fibonacci_race::lsystem::{self, MAX_LSYSTEM_GENERATIONS};
+use fibonacci_race::markdown;
+use fibonacci_race::morse;
+use fibonacci_race::numerals::{self, MAX_CHINESE_N};
+use fibonacci_race::oeis::{self, OeisSequence};
+use fibonacci_race::palette;
+use fibonacci_race::parity;
+use fibonacci_race::plugin::FibPlugin;
+use fibonacci_race::polynomial;
+use fibonacci_race::predict;
+use fibonacci_race::prime;
+use fibonacci_race::qr;
+use fibonacci_race::radix;
+use fibonacci_race::recurrence;
+use fibonacci_race::report::{humanize_duration, BenchmarkReport, OutputFormat};
+use fibonacci_race::roman;
+use fibonacci_race::scan;
+use fibonacci_race::sieve;
+use fibonacci_race::stats::{self, MAX_STATS_N};
+use fibonacci_race::text;
+use fibonacci_race::trie::FibTrie;
+use fibonacci_race::validate;
+use fibonacci_race::{
+    better_dynamic_fib, cached_fib_entries, cached_fib_stats, lookup_fib,
+    num_recursive_calls_backtrace, num_recursive_calls_memo, take_call_count, MAX_CALL_FORMULA_N,
+    MAX_FIB_INDEX_U128,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
 use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
 use std::time::{Duration, Instant};
+use terminal_size::{terminal_size, Width};
 
-#[test]
-fn test_each_version() {
-    assert_eq!(backtrace_fib(20), 6765);
-    assert_eq!(backtrace_memo_fib(&mut HashMap::new(), 20), 6765);
-    assert_eq!(dynamic_fib(20), 6765);
-    assert_eq!(better_dynamic_fib(20), 6765);
-    assert_eq!(cached_fib(20), 6765);
+// Settings for the race itself, pulled out of the command line flags
+struct Options {
+    format: OutputFormat,
+    call_count: bool,
+    explain: bool,
+    scale_plot: bool,
+    // cores to hand out round robin, one per algorithm, with --per-core
+    cores: Option<Vec<usize>>,
+    // what --quiet and the phi line at the end use to work out F(n)
+    algorithm: AlgorithmKind,
+    // just print F(n), no race
+    quiet: bool,
+    // show what cached_fib remembered after each race
+    dump_cache: bool,
+    // check every algorithm's F(n) really is F(n - 1) + F(n - 2)
+    validate_sequence: bool,
+    // with --cold-start, how many bytes to churn through before each
+    // timing so none of the algorithm's memory is still sitting in cache
+    cold_start: Option<usize>,
+    // how many times the race runs every algorithm
+    rounds: usize,
+    // a line per algorithm of how its time went from round to round
+    sparkline: bool,
+    // warn about any algorithm that got slower from one round to the next
+    monotone_check: bool,
+    // after the race, time the fastest algorithm this many more times
+    replay_winner: Option<usize>,
+    // a shared library from --plugin that races after the built in ones
+    plugin: Option<FibPlugin>,
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
 
-    if args.len() != 2 {
-        println!("Usage: {} n (positive integer from 2 to 186)", args[0]);
-        return;
+    match args.get(1).map(String::as_str) {
+        Some("--compress-demo") => return compress_demo(),
+        Some("--oeis") => return print_oeis(OeisSequence::Fibonacci),
+        Some("--oeis-lucas") => return print_oeis(OeisSequence::Lucas),
+        Some("--oeis-tribonacci") => return print_oeis(OeisSequence::Tribonacci),
+        Some("--automaton") => {
+            match args.get(2).and_then(|modulus| modulus.parse::<u128>().ok()) {
+                Some(modulus) if (1..=MAX_AUTOMATON_MODULUS).contains(&modulus) => {
+                    automaton(modulus)
+                }
+                _ => println!(
+                    "Usage: {} --automaton M (modulus from 1 to {})",
+                    args[0], MAX_AUTOMATON_MODULUS
+                ),
+            }
+            return;
+        }
+        Some("--parity") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(fib_num) => println!(
+                    "Fibonacci Number {} is {}",
+                    fib_num,
+                    if parity::fib_is_even(fib_num) {
+                        "even"
+                    } else {
+                        "odd"
+                    }
+                ),
+                None => println!("Usage: {} --parity n (any positive integer)", args[0]),
+            }
+            return;
+        }
+        Some("--compositions") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) => match compositions::fib_compositions(n) {
+                    Some(ways) => println!(
+                        "There are {} ways to write {} as an ordered sum of Fibonacci Numbers",
+                        ways, n
+                    ),
+                    None => println!(
+                        "There are too many ways to write {} as an ordered sum of Fibonacci Numbers to count in a u128",
+                        n
+                    ),
+                },
+                None => println!("Usage: {} --compositions n (positive integer)", args[0]),
+            }
+            return;
+        }
+        Some("--split-fib") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) => match compositions::split_fib(n) {
+                    Some((f1, f2)) => println!("{} = {} + {}", n, f1, f2),
+                    None => println!("{} isn't the sum of two Fibonacci Numbers", n),
+                },
+                None => println!("Usage: {} --split-fib n (positive integer)", args[0]),
+            }
+            return;
+        }
+        Some("--clock") => {
+            let time = args.get(2).and_then(|time| {
+                let (hours, minutes) = time.split_once(':')?;
+                Some((hours.parse::<u8>().ok()?, minutes.parse::<u8>().ok()?))
+            });
+            match time {
+                Some((hours, minutes)) if hours < 24 && minutes < 60 => {
+                    let render = args.get(3).map(String::as_str) == Some("--render");
+                    fibonacci_clock(hours, minutes, render)
+                }
+                _ => println!("Usage: {} --clock HH:MM [--render] (24 hour time)", args[0]),
+            }
+            return;
+        }
+        Some("--roman") => {
+            match args.get(2).and_then(|k| k.parse::<u32>().ok()) {
+                Some(k) => match roman::fib_roman(k) {
+                    Some(roman) => println!("Fibonacci Number {} is {}", k, roman),
+                    None => println!(
+                        "Fibonacci Number {} can't be written in Roman numerals (has to be 1 to {})",
+                        k,
+                        roman::MAX_ROMAN
+                    ),
+                },
+                None => println!("Usage: {} --roman k (positive integer)", args[0]),
+            }
+            return;
+        }
+        Some("--cultural-format") => {
+            let n = args.get(3).and_then(|n| n.parse::<u128>().ok());
+            match (args.get(2).map(String::as_str), n) {
+                (Some("chinese"), Some(n)) if n <= MAX_CHINESE_N => {
+                    println!("F({}) = {}", n, numerals::fib_to_chinese_numerals(n))
+                }
+                (Some("persian"), Some(n)) if n <= MAX_FIB_INDEX_U128 => {
+                    println!("F({}) = {}", n, numerals::fib_to_roman_perso(n).unwrap())
+                }
+                _ => println!(
+                    "Usage: {} --cultural-format chinese N (0 to {}) | persian N (0 to {})",
+                    args[0], MAX_CHINESE_N, MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--diff-table") => {
+            match args.get(2).and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if n <= MAX_DIFF_TABLE_N => difference_table(n),
+                _ => println!(
+                    "Usage: {} --diff-table N (0 to {})",
+                    args[0], MAX_DIFF_TABLE_N
+                ),
+            }
+            return;
+        }
+        Some("--digit-sums") => {
+            match args.get(2).and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if n as u128 <= MAX_FIB_INDEX_U128 => digit_sums(n),
+                _ => println!(
+                    "Usage: {} --digit-sums N (0 to {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--lsystem") => {
+            match args.get(2).and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if n <= MAX_LSYSTEM_GENERATIONS => {
+                    let symbols = lsystem::fibonacci_lsystem(n);
+                    println!("Generation {} is {} symbols long:", n, symbols.len());
+                    println!("{}", symbols);
+                }
+                _ => println!(
+                    "Usage: {} --lsystem N (0 to {})",
+                    args[0], MAX_LSYSTEM_GENERATIONS
+                ),
+            }
+            return;
+        }
+        Some("--morse") => {
+            match args.get(2).and_then(|k| k.parse::<u32>().ok()) {
+                Some(k) => match morse::fib_to_morse(k) {
+                    Some(morse) => println!("Fibonacci Number {} is {}", k, morse),
+                    None => println!(
+                        "Fibonacci Number {} is more than {}, too long to tap out",
+                        k,
+                        morse::MAX_MORSE
+                    ),
+                },
+                None => println!("Usage: {} --morse K", args[0]),
+            }
+            return;
+        }
+        Some("--unmorse") => {
+            match args.get(2) {
+                Some(code) => match morse::morse_to_fib(code) {
+                    Some(value) => println!("{} is {}", code, value),
+                    None => println!("{} isn't a Fibonacci Number in Morse code digits", code),
+                },
+                None => println!(
+                    "Usage: {} --unmorse MORSECODE (quoted, spaces between digits)",
+                    args[0]
+                ),
+            }
+            return;
+        }
+        Some("--diff") => {
+            match args.get(2).and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if n <= 184 => differences(n),
+                _ => println!("Usage: {} --diff N (0 to 184)", args[0]),
+            }
+            return;
+        }
+        Some("--base-phi") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) => println!("{} in base φ is {}", n, base_phi::to_base_phi(n)),
+                None => println!("Usage: {} --base-phi N", args[0]),
+            }
+            return;
+        }
+        Some("--fib-dist") => {
+            let a = args.get(2).and_then(|a| a.parse::<u128>().ok());
+            let b = args.get(3).and_then(|b| b.parse::<u128>().ok());
+            match (a, b) {
+                (Some(a), Some(b)) => println!(
+                    "{} is {} Fibonacci steps from {}",
+                    b,
+                    distance::fibonacci_distance(a, b),
+                    a
+                ),
+                _ => println!("Usage: {} --fib-dist A B", args[0]),
+            }
+            return;
+        }
+        Some("--radix") => {
+            let n = args.get(2).and_then(|n| n.parse::<u128>().ok());
+            let radix = args.get(3).and_then(|radix| radix.parse::<u32>().ok());
+            match (n, radix) {
+                (Some(n), Some(radix)) if n <= MAX_FIB_INDEX_U128 && (2..=36).contains(&radix) => {
+                    println!(
+                        "Fibonacci Number {} in base {} is {}",
+                        n,
+                        radix,
+                        radix::fib_to_radix_prefixed(n, radix)
+                    )
+                }
+                _ => println!(
+                    "Usage: {} --radix N RADIX (N from 0 to {}, RADIX from 2 to 36)",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--multibase") => {
+            let n = args.get(2).and_then(|n| n.parse::<u128>().ok());
+            let bases: Option<Vec<u32>> = args.get(3).and_then(|bases| {
+                bases
+                    .split(',')
+                    .map(|radix| radix.trim().parse::<u32>().ok())
+                    .collect()
+            });
+            match (n, bases) {
+                (Some(n), Some(bases))
+                    if n <= MAX_FIB_INDEX_U128
+                        && bases.iter().all(|radix| (2..=36).contains(radix)) =>
+                {
+                    print_multibase(n, &bases)
+                }
+                _ => println!(
+                    "Usage: {} --multibase N BASES (N from 0 to {}, BASES comma separated, each from 2 to 36)",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--full-multibase") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) if n <= MAX_FIB_INDEX_U128 => print_multibase(n, &radix::FULL_MULTIBASE),
+                _ => println!(
+                    "Usage: {} --full-multibase N (0 to {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some(flag @ "--fmt-sub") | Some(flag @ "--fmt-sup") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) if n <= MAX_FIB_INDEX_U128 => {
+                    if flag == "--fmt-sub" {
+                        println!("{}", numerals::fib_subscript(n));
+                    } else {
+                        println!("{}", numerals::fib_superscript(n));
+                    }
+                }
+                _ => println!(
+                    "Usage: {} {} N (0 to {})",
+                    args[0], flag, MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some(flag @ "--bin-width") | Some(flag @ "--hex-width") => {
+            let n = args.get(2).and_then(|n| n.parse::<u128>().ok());
+            let width = args.get(3).and_then(|width| width.parse::<u32>().ok());
+            match (n, width) {
+                (Some(n), Some(width)) if n <= MAX_FIB_INDEX_U128 => {
+                    let field = if flag == "--bin-width" {
+                        radix::fib_binary_width(n, width)
+                    } else {
+                        radix::fib_hex_width(n, width)
+                    };
+                    match field {
+                        Some(field) => println!("{}", field),
+                        None => println!(
+                            "Fibonacci Number {} = {} doesn't fit in {} bits",
+                            n,
+                            better_dynamic_fib(n),
+                            width
+                        ),
+                    }
+                }
+                _ => println!(
+                    "Usage: {} {} N W (N from 0 to {}, W in bits)",
+                    args[0], flag, MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--audio") => {
+            let n = args.get(2).and_then(|n| n.parse::<u8>().ok());
+            match (n, args.get(3)) {
+                (Some(n), Some(path)) if n as u128 <= MAX_FIB_INDEX_U128 => {
+                    let samples = audio::fib_audio_samples(n, 44_100, 250);
+                    match audio::write_wav(&samples, 44_100, path) {
+                        Ok(()) => println!(
+                            "Wrote F(1) through F({}) Hz, a quarter second each, to {}",
+                            n, path
+                        ),
+                        Err(err) => eprintln!("Couldn't write {}: {}", path, err),
+                    }
+                }
+                _ => println!(
+                    "Usage: {} --audio N OUTFILE (N from 0 to {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--palette") => {
+            match args.get(2).and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) => {
+                    for [r, g, b] in palette::fib_color_palette(n) {
+                        println!("#{:02X}{:02X}{:02X}", r, g, b);
+                    }
+                }
+                None => println!("Usage: {} --palette N (0 to 255 colors)", args[0]),
+            }
+            return;
+        }
+        Some("--popcount") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) if n <= MAX_FIB_INDEX_U128 => println!(
+                    "Fibonacci Number {} = {:b} has {} 1 bits",
+                    n,
+                    better_dynamic_fib(n),
+                    radix::fib_popcount(n)
+                ),
+                _ => println!(
+                    "Usage: {} --popcount N (0 to {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--fib-poly") => {
+            let n = args.get(2).and_then(|n| n.parse::<usize>().ok());
+            let x = args.get(3).and_then(|x| x.parse::<f64>().ok());
+            match (n, x) {
+                (Some(n), Some(x)) => println!(
+                    "Fibonacci polynomial f_{}({}) = {}",
+                    n,
+                    x,
+                    polynomial::fibonacci_polynomial(n, x)
+                ),
+                _ => println!(
+                    "Usage: {} --fib-poly N X (N a positive integer, X any number)",
+                    args[0]
+                ),
+            }
+            return;
+        }
+        Some("--factor") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) if (3..=factor::MAX_FACTOR_N).contains(&n) => println!(
+                    "Fibonacci Number {} is {} = {}",
+                    n,
+                    better_dynamic_fib(n),
+                    factor::format_factors(&factor::fibonacci_factors(n))
+                ),
+                Some(n) if n == 1 || n == 2 => {
+                    println!("Fibonacci Number {} is 1, which has no prime factors", n)
+                }
+                _ => println!(
+                    "Usage: {} --factor N (1 to {})",
+                    args[0],
+                    factor::MAX_FACTOR_N
+                ),
+            }
+            return;
+        }
+        Some("--next-prime") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) if n <= MAX_FIB_INDEX_U128 => println!(
+                    "The first prime from F({}) = {} on is {}",
+                    n,
+                    better_dynamic_fib(n),
+                    prime::next_prime_after_fib(n)
+                ),
+                _ => println!(
+                    "Usage: {} --next-prime N (0 to {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--prime-indices") => {
+            match args.get(2).and_then(|max| max.parse::<u32>().ok()) {
+                Some(max) if max <= prime::MAX_PRIME_INDEX => {
+                    let indices: Vec<String> = prime::fibonacci_prime_indices(max)
+                        .iter()
+                        .map(u32::to_string)
+                        .collect();
+                    if indices.is_empty() {
+                        println!("F(k) isn't prime for any k up to {}", max);
+                    } else {
+                        println!(
+                            "F(k) is prime for k = {} ({} of them up to {})",
+                            indices.join(", "),
+                            indices.len(),
+                            max
+                        );
+                    }
+                }
+                _ => println!(
+                    "Usage: {} --prime-indices MAX (0 to {})",
+                    args[0],
+                    prime::MAX_PRIME_INDEX
+                ),
+            }
+            return;
+        }
+        Some("--fib-gray") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) if n <= gray::MAX_FIB_GRAY => {
+                    let code = gray::fib_gray_encode(n);
+                    println!(
+                        "The Fibonacci Gray code for {} is {:0width$b}",
+                        n,
+                        code,
+                        width = gray::FIB_GRAY_WIDTH as usize
+                    );
+                    if n < gray::MAX_FIB_GRAY {
+                        let flipped = (code ^ gray::fib_gray_encode(n + 1)).trailing_zeros();
+                        println!("Going on to {} flips the F({}) digit", n + 1, flipped + 2);
+                    }
+                }
+                _ => println!(
+                    "Usage: {} --fib-gray N (0 to {})",
+                    args[0],
+                    gray::MAX_FIB_GRAY
+                ),
+            }
+            return;
+        }
+        Some("--bandwidth") => {
+            let len = args.get(2).and_then(|n| n.parse::<usize>().ok());
+            let reps = args.get(3).and_then(|reps| reps.parse::<usize>().ok());
+            match (len, reps) {
+                (Some(len), Some(reps))
+                    if (1..=bandwidth::MAX_BANDWIDTH_LEN).contains(&len) && reps > 0 =>
+                {
+                    memory_bandwidth(len, reps)
+                }
+                _ => println!(
+                    "Usage: {} --bandwidth N REPS (N values, 1 to {}, read and written REPS times)",
+                    args[0],
+                    bandwidth::MAX_BANDWIDTH_LEN
+                ),
+            }
+            return;
+        }
+        Some("--qr-info") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) if n <= MAX_FIB_INDEX_U128 => {
+                    let (count, version) = qr::fib_qr_metadata(n);
+                    println!(
+                        "F({}) = {} is {} alphanumeric characters, which fits a version {} QR code",
+                        n,
+                        qr::fib_to_alphanumeric(n),
+                        count,
+                        version
+                    );
+                }
+                _ => println!(
+                    "Usage: {} --qr-info N (0 to {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--table") => {
+            let start = args.get(2).and_then(|n| n.parse::<u128>().ok());
+            let end = args.get(3).and_then(|n| n.parse::<u128>().ok());
+            match (start, end) {
+                (Some(start), Some(end)) if start <= end && end <= MAX_FIB_INDEX_U128 => {
+                    print!("{}", markdown::fib_markdown_table(start, end))
+                }
+                _ => println!(
+                    "Usage: {} --table START END (0 <= START <= END <= {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--stats") => {
+            match args.get(2).and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if (1..=MAX_STATS_N).contains(&n) => fib_stats(n),
+                _ => println!("Usage: {} --stats N (1 to {})", args[0], MAX_STATS_N),
+            }
+            return;
+        }
+        Some("--checksum") => {
+            match args.get(2).and_then(|hex| parse_hex(hex)) {
+                Some(data) => {
+                    let checksum = checksum::fibonacci_checksum(&data);
+                    println!(
+                        "Fibonacci checksum of {} bytes: {:032x}",
+                        data.len(),
+                        checksum
+                    );
+                }
+                None => println!("Usage: {} --checksum DATA_HEX", args[0]),
+            }
+            return;
+        }
+        Some("--gen-data") => {
+            let len = args.get(2).and_then(|len| len.parse::<usize>().ok());
+            let encoding = args.get(3).map_or("HEX", String::as_str);
+            match (len, encoding) {
+                (Some(len), "HEX" | "BIN" | "BASE64") if len <= datagen::MAX_GEN_DATA_LEN => {
+                    generate_data(len, encoding)
+                }
+                _ => println!(
+                    "Usage: {} --gen-data LEN [HEX|BIN|BASE64] (LEN up to {} bytes)",
+                    args[0],
+                    datagen::MAX_GEN_DATA_LEN
+                ),
+            }
+            return;
+        }
+        Some("--prefix") => {
+            match args.get(2) {
+                Some(prefix)
+                    if !prefix.is_empty() && prefix.chars().all(|c| c.is_ascii_digit()) =>
+                {
+                    let found = FibTrie::build(MAX_FIB_INDEX_U128).prefix_search(prefix);
+                    println!(
+                        "{} Fibonacci Numbers (up to F({})) start with {}:",
+                        found.len(),
+                        MAX_FIB_INDEX_U128,
+                        prefix
+                    );
+                    for value in found {
+                        println!("  {}", value);
+                    }
+                }
+                _ => println!("Usage: {} --prefix DIGIT_PREFIX", args[0]),
+            }
+            return;
+        }
+        Some("--freq-text") => {
+            match args.get(2) {
+                Some(path) => match fs::read_to_string(path) {
+                    Ok(contents) => fibonacci_frequency(&contents),
+                    Err(err) => eprintln!("Couldn't read {}: {}", path, err),
+                },
+                None => println!("Usage: {} --freq-text FILE", args[0]),
+            }
+            return;
+        }
+        Some("--scan-fib") => {
+            match args.get(2) {
+                Some(path) => match fs::read(path) {
+                    Ok(data) => scan_fib(&data),
+                    Err(err) => eprintln!("Couldn't read {}: {}", path, err),
+                },
+                None => println!("Usage: {} --scan-fib FILE", args[0]),
+            }
+            return;
+        }
+        Some("--compress-estimate") => {
+            match args.get(2).and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if n <= MAX_ENTROPY_N => println!(
+                    "F(0) through F({}) as u128s have {:.3} bits of entropy per byte, so they should compress to about {:.1}% of {} bytes",
+                    n,
+                    entropy::sequence_entropy(n),
+                    entropy::compression_ratio_estimate(n) * 100.0,
+                    16 * (n + 1)
+                ),
+                _ => println!(
+                    "Usage: {} --compress-estimate N (0 to {})",
+                    args[0], MAX_ENTROPY_N
+                ),
+            }
+            return;
+        }
+        Some("--entropy") => {
+            match args.get(2).and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if (1..=MAX_ENTROPY_N).contains(&n) => println!(
+                    "Entropy of F(1) through F({}) is {} nats",
+                    n,
+                    entropy::fibonacci_entropy(n)
+                ),
+                _ => println!("Usage: {} --entropy N (1 to {})", args[0], MAX_ENTROPY_N),
+            }
+            return;
+        }
+        Some("--predict") => {
+            let series: Option<Vec<f64>> = args.get(2).and_then(|series| {
+                series
+                    .split(',')
+                    .map(|value| value.trim().parse::<f64>().ok())
+                    .collect()
+            });
+            match series {
+                Some(series)
+                    if series.len() >= 2
+                        && series.iter().all(|value| value.is_finite() && *value > 0.0) =>
+                {
+                    println!(
+                        "Next is probably about {}",
+                        predict::predict_next_fibonacci_like(&series)
+                    )
+                }
+                _ => println!(
+                    "Usage: {} --predict SERIES (at least two positive numbers, comma separated)",
+                    args[0]
+                ),
+            }
+            return;
+        }
+        Some("--recurrence") => {
+            let parse_list = |list: &String| -> Option<Vec<i64>> {
+                list.split(',')
+                    .map(|value| value.trim().parse::<i64>().ok())
+                    .collect()
+            };
+            let coefficients = args.get(2).and_then(parse_list);
+            let initial_values = args.get(3).and_then(parse_list);
+            let n = args.get(4).and_then(|n| n.parse::<usize>().ok());
+            match (coefficients, initial_values, n) {
+                (Some(coefficients), Some(initial_values), Some(n))
+                    if coefficients.len() == initial_values.len() =>
+                {
+                    match recurrence::checked_linear_recurrence(&coefficients, &initial_values, n) {
+                        Some(term) => println!("a({}) = {}", n, term),
+                        None => println!("a({}) is too big for an i64", n),
+                    }
+                }
+                _ => println!(
+                    "Usage: {} --recurrence COEFFS INITVALS N (comma separated, as many of each)",
+                    args[0]
+                ),
+            }
+            return;
+        }
+        Some("--checkpoint") => {
+            let with_every = args.get(3).map(String::as_str) == Some("--checkpoint-every");
+            let (every, n, expected_args) = if with_every {
+                (args.get(4), args.get(5), 6)
+            } else {
+                (None, args.get(3), 4)
+            };
+            let every = every.map_or(Some(DEFAULT_CHECKPOINT_EVERY), |every| {
+                every.parse::<u64>().ok()
+            });
+            let n = n.and_then(|n| n.parse::<u64>().ok());
+            match (args.get(2), every, n) {
+                (Some(path), Some(every), Some(n)) if every > 0 && args.len() == expected_args => {
+                    checkpointed_fib(Path::new(path), every, n)
+                }
+                _ => println!(
+                    "Usage: {} --checkpoint PATH [--checkpoint-every K] N (K is {} if not given)",
+                    args[0], DEFAULT_CHECKPOINT_EVERY
+                ),
+            }
+            return;
+        }
+        Some("--self-similar") => {
+            let values: Option<Vec<u128>> = args.get(2).and_then(|values| {
+                values
+                    .split(',')
+                    .map(|value| value.trim().parse::<u128>().ok())
+                    .collect()
+            });
+            match values {
+                Some(values) => {
+                    if scan::is_fibonacci_self_similar(&values) {
+                        println!("That's all Fibonacci numbers in a row");
+                    } else {
+                        println!("That isn't all Fibonacci numbers in a row");
+                    }
+                    match scan::longest_fibonacci_subsequence(&values) {
+                        (_, 0) => println!("None of them are Fibonacci numbers"),
+                        (k, length) => println!(
+                            "The longest run is {} long, F({}) through F({})",
+                            length,
+                            k,
+                            k + length - 1
+                        ),
+                    }
+                }
+                None => println!("Usage: {} --self-similar VALUES (comma separated)", args[0]),
+            }
+            return;
+        }
+        Some("--div-sieve") => {
+            match args.get(2).and_then(|limit| limit.parse::<u128>().ok()) {
+                Some(limit) if limit <= 10_000_000 => divisibility_sieve(limit),
+                _ => println!("Usage: {} --div-sieve LIMIT (up to 10000000)", args[0]),
+            }
+            return;
+        }
+        Some("--convergence") => {
+            match args.get(2).and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if n <= 185 => convergence_table(n),
+                _ => println!("Usage: {} --convergence N (up to 185)", args[0]),
+            }
+            return;
+        }
+        Some("--recip-sum") => {
+            match args.get(2).and_then(|n| n.parse::<u32>().ok()) {
+                Some(n) if (1..=MAX_FIB_INDEX_U128 as u32).contains(&n) => println!(
+                    "1/F(1) + ... + 1/F({}) = {} (about {:e} short of {})",
+                    n,
+                    convergence::reciprocal_fibonacci_sum(n),
+                    convergence::reciprocal_fibonacci_sum_error(n),
+                    convergence::RECIPROCAL_FIBONACCI_CONSTANT
+                ),
+                _ => println!(
+                    "Usage: {} --recip-sum N (1 to {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--binet-interval") => {
+            match args.get(2).and_then(|n| n.parse::<u32>().ok()) {
+                Some(n) if n as u128 <= MAX_FIB_INDEX_U128 => {
+                    let interval = interval::binet_fib_interval(n);
+                    println!(
+                        "Binet says F({}) is somewhere in {} (width {:e})",
+                        n,
+                        interval,
+                        interval.width()
+                    );
+                    if interval.pins_down() {
+                        println!("which only has {} in it", lookup_fib(n as u128));
+                    } else {
+                        println!(
+                            "which has {} in it, but its neighbours too",
+                            lookup_fib(n as u128)
+                        );
+                    }
+                }
+                _ => println!(
+                    "Usage: {} --binet-interval N (0 to {})",
+                    args[0], MAX_FIB_INDEX_U128
+                ),
+            }
+            return;
+        }
+        Some("--call-formula") => {
+            match args.get(2).and_then(|n| n.parse::<u128>().ok()) {
+                Some(n) if n <= MAX_CALL_FORMULA_N => {
+                    println!(
+                        "backtrace_fib({}) makes 2 F({}) - 1 = {} calls",
+                        n,
+                        n + 1,
+                        num_recursive_calls_backtrace(n)
+                    );
+                    println!(
+                        "backtrace_memo_fib({}) makes {} calls",
+                        n,
+                        num_recursive_calls_memo(n)
+                    );
+                }
+                _ => println!(
+                    "Usage: {} --call-formula N (0 to {})",
+                    args[0], MAX_CALL_FORMULA_N
+                ),
+            }
+            return;
+        }
+        Some("--demo-backoff") => return demo_backoff(),
+        Some("--stream") => {
+            match args.get(2).map(|name| AlgorithmKind::from_name(name)) {
+                None => stream(AlgorithmKind::BetterDynamic),
+                Some(Some(kind)) => stream(kind),
+                Some(None) => println!(
+                    "Usage: {} --stream [ALGORITHM] (one of {})",
+                    args[0],
+                    AlgorithmKind::names().join(", ")
+                ),
+            }
+            return;
+        }
+        _ => {}
     }
 
-    let fib_num = args[1].parse::<u128>().unwrap();
-    if fib_num < 2 {
-        println!("Come on, Fibonacci Number {} is really boring", fib_num);
+    let format = if take_flag(&mut args, "--latex") {
+        OutputFormat::Latex
+    } else if take_flag(&mut args, "--json") {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Text
+    };
+    let algorithm =
+        take_value(&mut args, "--algorithm").map(|name| AlgorithmKind::from_name(&name));
+    let cold_start = take_flag(&mut args, "--cold-start");
+    let cache_size_kb = take_value(&mut args, "--cache-size-kb").map(|kb| kb.parse::<usize>());
+    let replay_winner = take_value(&mut args, "--replay-winner").map(|n| n.parse::<usize>());
+    let rounds = take_value(&mut args, "--rounds").map(|n| n.parse::<usize>());
+    let plugin = match take_value(&mut args, "--plugin") {
+        Some(path) => match FibPlugin::load(&path) {
+            Ok(plugin) => Some(plugin),
+            Err(err) => {
+                println!("Couldn't load plugin {}: {}", path, err);
+                return;
+            }
+        },
+        None => None,
+    };
+    let options = Options {
+        format,
+        call_count: take_flag(&mut args, "--call-count"),
+        explain: take_flag(&mut args, "--explain"),
+        scale_plot: take_flag(&mut args, "--scale-plot"),
+        cores: if take_flag(&mut args, "--per-core") {
+            let cores = affinity::available_cores();
+            if cores.is_empty() {
+                eprintln!("--per-core only works on Linux, racing without pinning to cores");
+                None
+            } else {
+                Some(cores)
+            }
+        } else {
+            None
+        },
+        algorithm: algorithm.flatten().unwrap_or(AlgorithmKind::Cached),
+        quiet: take_flag(&mut args, "--quiet"),
+        dump_cache: take_flag(&mut args, "--dump-cache"),
+        validate_sequence: take_flag(&mut args, "--validate-sequence"),
+        cold_start: match &cache_size_kb {
+            _ if !cold_start => None,
+            Some(Ok(kb)) => Some(kb * 1024),
+            _ => Some(DEFAULT_CACHE_SIZE_KB * 1024),
+        },
+        rounds: match rounds {
+            Some(Ok(rounds)) => rounds,
+            _ => DEFAULT_ROUNDS,
+        },
+        sparkline: take_flag(&mut args, "--sparkline"),
+        monotone_check: take_flag(&mut args, "--monotone-check"),
+        replay_winner: match replay_winner {
+            Some(Ok(times)) => Some(times),
+            _ => None,
+        },
+        plugin,
+    };
+
+    let random_n = take_flag(&mut args, "--random-n");
+    let seed = take_value(&mut args, "--seed").map(|seed| seed.parse::<u64>());
+    let n_sequence = take_value(&mut args, "--n-sequence");
+    let parallel_batch = take_value(&mut args, "--parallel-batch");
+    let watch = take_flag(&mut args, "--watch");
+    let watch_interval = take_value(&mut args, "--watch-interval").map(|secs| secs.parse::<f64>());
+
+    let max_fib_index = max_fib_index();
+    let modes = [random_n, n_sequence.is_some(), parallel_batch.is_some()];
+    let mode_count = modes.iter().filter(|&&mode| mode).count();
+    let expected_args = if mode_count > 0 { 1 } else { 2 };
+    if args.len() != expected_args
+        || matches!(seed, Some(Err(_)))
+        || mode_count > 1
+        || matches!(algorithm, Some(None))
+        || matches!(watch_interval, Some(Err(_)))
+        || matches!(watch_interval, Some(Ok(secs)) if !(secs >= 0.0 && secs.is_finite()))
+        || ((watch || watch_interval.is_some())
+            && (n_sequence.is_some() || parallel_batch.is_some()))
+        || matches!(cache_size_kb, Some(Err(_)) | Some(Ok(0)))
+        || (cache_size_kb.is_some() && !cold_start)
+        || matches!(replay_winner, Some(Err(_)) | Some(Ok(0)))
+        || (replay_winner.is_some() && options.quiet)
+        || matches!(rounds, Some(Err(_)) | Some(Ok(0)))
+    {
+        let flags = "[--latex | --json] [--call-count] [--explain] [--scale-plot] [--per-core] [--algorithm ALGORITHM] [--quiet] [--dump-cache] [--validate-sequence] [--cold-start [--cache-size-kb KB]] [--replay-winner N] [--rounds N] [--sparkline] [--monotone-check] [--plugin PATH]";
+        println!(
+            "Usage: {} {} n (positive integer from 2 to {})",
+            args[0], flags, max_fib_index
+        );
+        println!("   or: {} {} --random-n [--seed SEED]", args[0], flags);
+        println!(
+            "   or: {} {} --watch [--watch-interval SECS] (n | --random-n)",
+            args[0], flags
+        );
+        println!("   or: {} {} --n-sequence n,n,...", args[0], flags);
+        println!(
+            "   or: {} [--algorithm ALGORITHM] --parallel-batch FILE (one n per line)",
+            args[0]
+        );
+        println!(
+            "ALGORITHM is one of {} (cached if not given)",
+            AlgorithmKind::names().join(", ")
+        );
         return;
-    } else if fib_num > 186 {
-        println!("That's gonna get too big to calculate Phi, lets try something smaller");
+    }
+
+    if let Some(n_sequence) = n_sequence {
+        return race_sequence(&options, &n_sequence);
+    }
+    if let Some(path) = parallel_batch {
+        // not the cached default, its cache is behind one big lock
+        let kind = algorithm.flatten().unwrap_or(AlgorithmKind::BetterDynamic);
+        return run_parallel_batch(&path, kind);
+    }
+
+    let fib_num = if random_n {
+        let fib_num = match seed {
+            Some(Ok(seed)) => StdRng::seed_from_u64(seed).gen_range(2..=max_fib_index),
+            _ => rand::thread_rng().gen_range(2..=max_fib_index),
+        };
+        match options.format {
+            // off to the side, so --quiet only prints the answer and
+            // --json output still parses
+            _ if options.quiet => eprintln!("Randomly picked n = {}", fib_num),
+            OutputFormat::Json => eprintln!("Randomly picked n = {}", fib_num),
+            // a LaTeX comment, so --latex output still compiles
+            OutputFormat::Latex => println!("% Randomly picked n = {}", fib_num),
+            OutputFormat::Text => println!("Randomly picked n = {}", fib_num),
+        }
+        fib_num
+    } else {
+        match args[1].parse::<u128>() {
+            Ok(fib_num) => fib_num,
+            Err(_) => {
+                println!("{} isn't a Fibonacci Number index", args[1]);
+                return;
+            }
+        }
+    };
+    if let Err(complaint) = check_fib_num(&options, fib_num) {
+        println!("{}", complaint);
         return;
     }
 
-    println!("\nThe first time solving will be the slowest\n");
-    solve_each(fib_num);
-    println!("What about solving it a second or third time, anyone faster this time?\n");
-    solve_each(fib_num);
-    solve_each(fib_num);
+    if watch || watch_interval.is_some() {
+        let interval = watch_interval.map(|secs| Duration::from_secs_f64(secs.unwrap()));
+        return watch_race(&options, fib_num, interval);
+    }
 
-    let answer = cached_fib(fib_num);
-    let phi = (cached_fib(fib_num) as f64) / (cached_fib(fib_num - 1) as f64);
-    println!(
-        "By the way, Fibonacci Number {} is {} which (divided by Fib Num {}) approximates phi as {}",
-        fib_num,
-        answer,
-        fib_num - 1,
-        phi
-    );
+    let report = race(&options, fib_num);
+    if options.format == OutputFormat::Json {
+        println!("{}", report.to_json());
+    }
 }
 
-fn solve_each(fib_num: u128) {
-    let now = Instant::now();
-    let _ = backtrace_fib(fib_num);
-    let elapsed = now.elapsed();
-    print_results(fib_num, "simple backtracing/recursion", elapsed);
+// One round of the race at a time, again each time Enter gets pressed
+// (or every interval, if there is one) until stdin runs out or Ctrl+C
+fn watch_race(options: &Options, fib_num: u128, interval: Option<Duration>) {
+    let stdin = io::stdin();
+    for round in 1.. {
+        // kept out of the way of output that something else has to parse
+        match options.format {
+            OutputFormat::Text => println!("=== round {} ===", round),
+            OutputFormat::Latex => println!("% === round {} ===", round),
+            OutputFormat::Json => eprintln!("=== round {} ===", round),
+        }
+        let mut report = BenchmarkReport::default();
+        solve_each(options, &mut report, fib_num);
+        match options.format {
+            OutputFormat::Latex => println!("{}", report.to_latex_tabular()),
+            OutputFormat::Json => println!("{}", report.to_json()),
+            OutputFormat::Text => {}
+        }
 
-    let now = Instant::now();
-    let _ = backtrace_memo_fib(&mut HashMap::new(), fib_num);
-    let elapsed = now.elapsed();
-    print_results(fib_num, "backtracing/recursion with memoization", elapsed);
+        match interval {
+            Some(interval) => thread::sleep(interval),
+            None => {
+                let mut line = String::new();
+                if matches!(stdin.lock().read_line(&mut line), Ok(0) | Err(_)) {
+                    break;
+                }
+            }
+        }
+    }
+}
 
-    let now = Instant::now();
-    let _ = dynamic_fib(fib_num);
-    let elapsed = now.elapsed();
-    print_results(
-        fib_num,
-        "dynamic programming with memoization via HashMap",
-        elapsed,
-    );
+// Why fib_num can't be raced, if there's a reason
+fn check_fib_num(options: &Options, fib_num: u128) -> Result<(), String> {
+    if fib_num < 2 {
+        Err(format!(
+            "Come on, Fibonacci Number {} is really boring",
+            fib_num
+        ))
+    } else if fib_num > max_fib_index() {
+        Err("That's gonna get too big to calculate Phi, lets try something smaller".to_string())
+    } else if options.explain && fib_num > MAX_EXPLAIN_N {
+        Err(format!(
+            "Explaining fib:{} would take forever, try {} or less",
+            fib_num, MAX_EXPLAIN_N
+        ))
+    } else {
+        Ok(())
+    }
+}
 
-    let now = Instant::now();
-    let _ = better_dynamic_fib(fib_num);
-    let elapsed = now.elapsed();
-    print_results(
-        fib_num,
-        "dynamic programming with memoization via tuple",
-        elapsed,
+// The whole race for one n: three rounds (or --rounds) of every algorithm and
+// whatever the format prints at the end. JSON is left to the caller,
+// since a sequence of races all goes in one array
+fn race(options: &Options, fib_num: u128) -> BenchmarkReport {
+    if options.explain {
+        // stderr, so the timings on stdout stay clean
+        for &kind in &AlgorithmKind::ALL {
+            for line in explain::explain(kind, fib_num) {
+                eprintln!("{}", line);
+            }
+            eprintln!();
+        }
+    }
+
+    let mut report = BenchmarkReport::default();
+    if options.quiet {
+        println!("{}", options.algorithm.compute(fib_num));
+        finish_race(options, fib_num);
+        return report;
+    }
+
+    let chatty = options.format == OutputFormat::Text;
+    if chatty {
+        println!("\nThe first time solving will be the slowest\n");
+    }
+    let mut results = solve_each(options, &mut report, fib_num);
+    if chatty && options.rounds > 1 {
+        println!("What about solving it a second or third time, anyone faster this time?\n");
+    }
+    for _ in 1..options.rounds {
+        results.extend(solve_each(options, &mut report, fib_num));
+    }
+    if options.monotone_check {
+        monotone_check(options, &report);
+    }
+    if options.sparkline {
+        for line in report.to_sparklines() {
+            print_aside(options, &format!("  {}", line));
+        }
+        if chatty {
+            println!();
+        }
+    }
+    if let Some(times) = options.replay_winner {
+        replay_winner(options, &results, times);
+    }
+
+    match options.format {
+        OutputFormat::Latex => println!("{}", report.to_latex()),
+        OutputFormat::Json => {}
+        OutputFormat::Text => {
+            let answer = options.algorithm.compute(fib_num);
+            let phi = (answer as f64) / (options.algorithm.compute(fib_num - 1) as f64);
+            println!(
+                "By the way, Fibonacci Number {} is {} which (divided by Fib Num {}) approximates phi as {}",
+                fib_num,
+                answer,
+                fib_num - 1,
+                phi
+            );
+        }
+    }
+    finish_race(options, fib_num);
+    report
+}
+
+// Every algorithm's round over round change, and a warning for each one
+// that didn't only get faster. With the caches warming up that's what
+// should happen, so a slower round means something else got in the way
+// (another process, the CPU changing speed)
+fn monotone_check(options: &Options, report: &BenchmarkReport) {
+    for trend in report.round_trends() {
+        let changes: Vec<String> = trend
+            .changes
+            .iter()
+            .map(|change| format!("{:+.1}%", change))
+            .collect();
+        print_aside(
+            options,
+            &format!("  {}: {}", trend.algorithm, changes.join(", ")),
+        );
+        if !trend.monotone {
+            print_aside(
+                options,
+                &format!(
+                    "  WARNING: {} got slower from one round to the next (scheduler noise or CPU frequency changes?)",
+                    trend.algorithm
+                ),
+            );
+        }
+    }
+    if options.format == OutputFormat::Text {
+        println!();
+    }
+}
+
+// Run whichever algorithm had the single fastest time in the race again
+// (under the same conditions) times more times, to see if it really is
+// that fast or just got lucky once
+fn replay_winner(options: &Options, results: &[AlgorithmResult], times: usize) {
+    let winner = match algorithm::fastest(results) {
+        Some(winner) => winner,
+        None => return,
+    };
+    let replay: Vec<AlgorithmResult> = (0..times)
+        .map(|_| time_compute(winner.kind, winner.n, options.cold_start).0)
+        .collect();
+    take_call_count();
+    let (mean, stddev) = algorithm::mean_and_stddev(&replay);
+    print_aside(
+        options,
+        &format!(
+            "Replaying the winner ({}) {} more times took {:.1} ± {:.1} ns",
+            winner.kind.description(),
+            times,
+            mean,
+            stddev
+        ),
     );
+}
+
+// The checks and extra info that can come after a race (or --quiet)
+fn finish_race(options: &Options, fib_num: u128) {
+    if options.validate_sequence {
+        validate_each(options, fib_num);
+    }
+    if options.dump_cache {
+        dump_cache(options);
+    }
+}
 
+// Extra output beyond the race itself goes on stdout next to plain text
+// output, but on stderr for anything else, to leave stdout alone
+fn print_aside(options: &Options, line: &str) {
+    if options.format == OutputFormat::Text && !options.quiet {
+        println!("{}", line);
+    } else {
+        eprintln!("{}", line);
+    }
+}
+
+// Run every algorithm again for n - 2 and n - 1 too, and complain about
+// any that don't add up
+fn validate_each(options: &Options, fib_num: u128) {
+    let mut lines = Vec::new();
+    for &kind in &AlgorithmKind::ALL {
+        if let Err(mismatch) = validate::validate_sequence(|n| kind.compute(n), fib_num) {
+            lines.push(format!("{} failed validation: {}", kind.name(), mismatch));
+        }
+    }
+    if lines.is_empty() {
+        lines.push(format!(
+            "All {} algorithms agree F({}) = F({}) + F({})",
+            AlgorithmKind::ALL.len(),
+            fib_num,
+            fib_num - 1,
+            fib_num - 2
+        ));
+    }
+
+    for line in lines {
+        print_aside(options, &line);
+    }
+}
+
+// Every (n, F(n)) cached_fib has held on to, plus how well the cache
+// did
+fn dump_cache(options: &Options) {
+    let entries = cached_fib_entries();
+    let (hits, misses) = cached_fib_stats();
+    let mut lines = vec![format!(
+        "cached_fib has {} entries, after {} hits and {} misses ({:.1}% hit rate)",
+        entries.len(),
+        hits,
+        misses,
+        100.0 * hits as f64 / (hits + misses).max(1) as f64
+    )];
+    for (fib_num, answer) in entries {
+        lines.push(format!("  F({}) = {}", fib_num, answer));
+    }
+
+    for line in lines {
+        print_aside(options, &line);
+    }
+}
+
+// Race each n in a comma separated list, one after the other. A bad n
+// just gets complained about and skipped, the rest still run
+fn race_sequence(options: &Options, n_sequence: &str) {
+    let mut reports = Vec::new();
+    for value in n_sequence.split(',').map(str::trim) {
+        if options.format != OutputFormat::Json {
+            println!("=== n={} ===", value);
+        }
+        let checked = value
+            .parse::<u128>()
+            .map_err(|_| format!("{} isn't a Fibonacci Number index", value))
+            .and_then(|fib_num| check_fib_num(options, fib_num).map(|_| fib_num));
+        match checked {
+            Ok(fib_num) => reports.push(race(options, fib_num)),
+            // stderr in JSON mode, so the array still parses
+            Err(complaint) if options.format == OutputFormat::Json => {
+                eprintln!("n={}: {}", value, complaint)
+            }
+            Err(complaint) => println!("{}", complaint),
+        }
+    }
+
+    if options.format == OutputFormat::Json {
+        let races: Vec<String> = reports.iter().map(BenchmarkReport::to_json).collect();
+        if races.is_empty() {
+            println!("[]");
+        } else {
+            println!("[\n{}\n]", races.join(",\n"));
+        }
+    }
+}
+
+// Solve every n in the file (one per line) all at once across however
+// many cores rayon finds. Results are collected and sorted by n before
+// anything is printed, so the output never comes out interleaved
+fn run_parallel_batch(path: &str, kind: AlgorithmKind) {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            println!("Couldn't read {}: {}", path, err);
+            return;
+        }
+    };
+
+    let max_fib_index = max_fib_index();
+    let mut fib_nums = Vec::new();
+    for (line_num, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse::<u128>() {
+            Ok(fib_num) if fib_num <= max_fib_index => fib_nums.push(fib_num),
+            _ => eprintln!(
+                "{}:{}: {} isn't a Fibonacci Number index from 0 to {}",
+                path,
+                line_num + 1,
+                line,
+                max_fib_index
+            ),
+        }
+    }
+
+    let mut results: Vec<(u128, u128, Duration)> = fib_nums
+        .par_iter()
+        .map(|&fib_num| {
+            let now = Instant::now();
+            let answer = kind.compute(fib_num);
+            (fib_num, answer, now.elapsed())
+        })
+        .collect();
+    results.sort_by_key(|&(fib_num, _, _)| fib_num);
+
+    for (fib_num, answer, elapsed) in results {
+        println!("F({}) = {} [{} ns]", fib_num, answer, elapsed.as_nanos());
+    }
+}
+
+// Pull a bare flag out of the args (wherever it is), returning whether
+// it was there at all
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    match args.iter().position(|arg| arg == flag) {
+        Some(pos) => {
+            args.remove(pos);
+            true
+        }
+        None => false,
+    }
+}
+
+// Pull a flag and the value right after it out of the args. If the
+// value is missing the flag is left behind, so the usage check trips
+fn take_value(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(pos) if pos + 1 < args.len() => {
+            args.remove(pos);
+            Some(args.remove(pos))
+        }
+        _ => None,
+    }
+}
+
+// One round of every algorithm, handing back their results too so the
+// winner can be picked out
+fn solve_each(
+    options: &Options,
+    report: &mut BenchmarkReport,
+    fib_num: u128,
+) -> Vec<AlgorithmResult> {
+    report.start_round();
+    take_call_count();
+
+    let mut results = Vec::new();
+
+    for (i, &kind) in AlgorithmKind::ALL.iter().enumerate() {
+        let core = options.cores.as_ref().map(|cores| cores[i % cores.len()]);
+        let cold_start = options.cold_start;
+        // a pinned run gets its own thread, so only it is stuck on that core
+        let (result, calls) = match core {
+            Some(core) => thread::spawn(move || {
+                if let Err(err) = affinity::pin_to_core(core) {
+                    eprintln!("Couldn't pin {} to core {}: {}", kind.name(), core, err);
+                }
+                time_compute(kind, fib_num, cold_start)
+            })
+            .join()
+            .unwrap(),
+            None => time_compute(kind, fib_num, cold_start),
+        };
+        print_results(
+            options,
+            report,
+            fib_num,
+            kind.description(),
+            core,
+            result.elapsed,
+            calls,
+        );
+        results.push(result);
+    }
+    if let Some(plugin) = &options.plugin {
+        race_plugin(options, report, plugin, fib_num);
+    }
+
+    if options.format == OutputFormat::Text {
+        println!();
+        if options.scale_plot {
+            let width = terminal_size()
+                .map(|(Width(width), _)| width as usize)
+                .unwrap_or(80);
+            for line in report.to_scale_plot(width.saturating_sub(2)) {
+                println!("  {}", line);
+            }
+            println!();
+        }
+    }
+    results
+}
+
+// The plugin gets timed the same way as the rest, but it isn't one of
+// the AlgorithmKinds so it won't show up in anything after the race that
+// needs one (--replay-winner, --validate-sequence). It's someone else's
+// code, so its answer gets checked too
+fn race_plugin(options: &Options, report: &mut BenchmarkReport, plugin: &FibPlugin, fib_num: u128) {
     let now = Instant::now();
-    let _ = cached_fib(fib_num);
+    let value = plugin.compute(fib_num);
     let elapsed = now.elapsed();
-    print_results(fib_num, "cached function", elapsed);
+    print_results(options, report, fib_num, plugin.name(), None, elapsed, 0);
+    if value != lookup_fib(fib_num) {
+        print_aside(
+            options,
+            &format!(
+                "  {} says F({}) is {}, but it's {}",
+                plugin.name(),
+                fib_num,
+                value,
+                lookup_fib(fib_num)
+            ),
+        );
+    }
+}
 
-    println!();
+// How long kind takes, and how many calls it made doing it (counted
+// per thread, so this has to happen on the thread that did the work,
+// which is also the core whose caches need emptying for a cold start)
+fn time_compute(
+    kind: AlgorithmKind,
+    fib_num: u128,
+    cold_start: Option<usize>,
+) -> (AlgorithmResult, u64) {
+    if let Some(size) = cold_start {
+        evict_caches(size);
+    }
+    let result = algorithm::benchmark(kind, fib_num);
+    (result, take_call_count())
+}
+
+// Often enough to not lose much, not so often that it's all writing
+// files, at least for the millions of steps where it's worth it
+const DEFAULT_CHECKPOINT_EVERY: u64 = 10_000;
+
+// The first round (everything cold) and then two more to see who
+// speeds up
+const DEFAULT_ROUNDS: usize = 3;
+
+// A typical L2 is somewhere around 256KB to 1MB per core
+const DEFAULT_CACHE_SIZE_KB: usize = 512;
+
+// CPU caches keep whatever was touched most recently, so writing and
+// then reading back a buffer at least as big as the cache pushes out
+// everything that was in there before (the previous round's memo, the
+// code that ran last), and the timing after this starts cold. The data
+// is random so none of it can be skipped as all zeros, and black_box
+// keeps the compiler from deciding the sum is never used and dropping
+// the whole loop. It won't empty a big shared L3, so make the size
+// bigger with --cache-size-kb to go after that too
+fn evict_caches(size: usize) {
+    let mut buffer = vec![0u8; size];
+    rand::thread_rng().fill(&mut buffer[..]);
+    let sum: u64 = buffer.iter().map(|&byte| byte as u64).sum();
+    std::hint::black_box(sum);
+}
+
+// Results always go in the report, but only text gets printed right
+// away, the other formats need the whole report before they can print
+fn print_results(
+    options: &Options,
+    report: &mut BenchmarkReport,
+    fib_num: u128,
+    desc: &str,
+    core: Option<usize>,
+    elapsed: Duration,
+    calls: u64,
+) {
+    report.record(desc, fib_num, elapsed);
+    if options.format == OutputFormat::Text {
+        print!("  Solving fib:{} with {:49}", fib_num, desc);
+        if let Some(core) = core {
+            print!(" on core {:>3}", core);
+        }
+        print!(
+            " took {:>15} ns {:>10}",
+            elapsed.as_nanos(),
+            humanize_duration(elapsed.as_nanos())
+        );
+        if options.call_count && calls > 0 {
+            print!(" making {:>12} calls", calls);
+        }
+        println!();
+    }
+}
+
+// F(n) however big, saving progress to path as it goes
+fn checkpointed_fib(path: &Path, every: u64, n: u64) {
+    match bigint::read_checkpoint(path) {
+        Ok(Some(checkpoint)) if checkpoint.i <= n => {
+            eprintln!("Picking up from F({}) in {}", checkpoint.i, path.display())
+        }
+        Ok(Some(checkpoint)) => eprintln!(
+            "{} is already at F({}), past F({}), so working it out without it",
+            path.display(),
+            checkpoint.i,
+            n
+        ),
+        _ => {}
+    }
+    match bigint::bigint_fib_checkpointed(n, path, every) {
+        Ok(fib) => println!("{}", fib),
+        Err(err) => eprintln!("Couldn't use checkpoint {}: {}", path.display(), err),
+    }
 }
 
-fn print_results(fib_num: u128, desc: &str, elapsed: Duration) {
+// F(n) in each of the bases, a line each
+fn print_multibase(n: u128, bases: &[u32]) {
+    println!("Fibonacci Number {}", n);
+    for (radix, digits) in bases.iter().zip(radix::fib_multibase(n, bases)) {
+        println!("  base {:>2}: {}", radix, digits);
+    }
+}
+
+// F(n) next to its first and second differences, to see the sequence
+// show up again (shifted back) in both
+fn differences(n: usize) {
+    let first = diff::fibonacci_diff_sequence(n + 1);
+    let second = diff::fibonacci_second_diff_sequence(n + 1);
     println!(
-        "  Solving fib:{} with {:49} took {:>15} ns",
-        fib_num,
-        desc,
-        elapsed.as_nanos()
+        "{:>4} {:>40} {:>40} {:>40}",
+        "n", "F(n)", "F(n+1) - F(n)", "second difference"
     );
+    for (fib_num, fib) in fibonacci_iter().take(n + 1).enumerate() {
+        println!(
+            "{:>4} {:>40} {:>40} {:>40}",
+            fib_num, fib, first[fib_num], second[fib_num]
+        );
+    }
 }
 
-// Simple recursion to backtrace our way backwards down the chain
-// to 2 (which gets fixed answers for 0 and 1) and then unwinds to
-// get the answer
-fn backtrace_fib(fib_num: u128) -> u128 {
-    if fib_num == 0 || fib_num == 1 {
-        return fib_num;
+// Every row of the difference table lined up in columns as wide as the
+// widest number anywhere in it
+fn difference_table(n: usize) {
+    let table = diff::fibonacci_difference_table(n);
+    let width = table
+        .iter()
+        .flatten()
+        .map(|value| value.to_string().len())
+        .max()
+        .unwrap();
+    for (k, row) in table.iter().enumerate() {
+        let row: Vec<String> = row
+            .iter()
+            .map(|value| format!("{:>width$}", value, width = width))
+            .collect();
+        println!("Δ^{:<3} {}", k, row.join(" "));
     }
-    backtrace_fib(fib_num - 1) + backtrace_fib(fib_num - 2)
 }
 
-// Simliar to above, but brings in a HashMap for memoization (weird
-// that this is the only way to keep the memo around for future calls)
-// otherwise, works the same as the backtrace before, it is justs faster
-// because of the memoization
-fn backtrace_memo_fib(memo: &mut HashMap<u128, u128>, fib_num: u128) -> u128 {
-    match memo.get(&fib_num).map(|answer| answer.clone()) {
-        Some(result) => result,
-        None => {
-            let result = match fib_num {
-                0 | 1 => fib_num,
-                n => backtrace_memo_fib(memo, n - 1) + backtrace_memo_fib(memo, n - 2),
-            };
-            memo.insert(fib_num, result.clone());
-            result
-        }
+fn fib_stats(n: usize) {
+    let stats = stats::fib_stats(n).unwrap();
+    if stats.approximate {
+        println!(
+            "Warning: F(187) and up don't fit in a u128, so these are only f64 approximations"
+        );
     }
+    println!("Stats for F(0) through F({}):", n - 1);
+    println!("  minimum:            {}", stats.min);
+    println!("  maximum:            {}", stats.max);
+    println!("  mean:               {}", stats.mean);
+    println!("  median:             {}", stats.median);
+    println!("  standard deviation: {}", stats.std_dev);
+    println!("  closest to mean:    F({})", stats.closest_to_mean);
 }
 
-// Loop thru the chain purposely instead of relying on recursion
-// This code is less idiomatic of Rust though, uses an overly complex
-// HashMap and keeps the entire chain in memory (see next method)
-fn dynamic_fib(fib_num: u128) -> u128 {
-    let mut memo = HashMap::new();
-    memo.insert(0, 0);
-    memo.insert(1, 1);
-    match fib_num {
-        0 | 1 => {} // already set
-        n => {
-            for i in 2..=n {
-                let result = *memo.get(&(i - 1)).unwrap() + *memo.get(&(i - 2)).unwrap();
-                memo.insert(i, result);
-            }
+// Pairs of hex digits into bytes, or None if it isn't hex
+fn parse_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+// Fibonacci test data as hex, base64 or just the raw bytes
+fn generate_data(len: usize, encoding: &str) {
+    let data = datagen::generate_test_data(len);
+    match encoding {
+        "BIN" => io::stdout().write_all(&data).unwrap(),
+        "BASE64" => println!("{}", datagen::to_base64(&data)),
+        _ => println!(
+            "{}",
+            data.iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>()
+        ),
+    }
+}
+
+// How often each Fibonacci number turns up in some text, smallest first
+fn fibonacci_frequency(contents: &str) {
+    let mut counts: Vec<(u128, usize)> = text::count_fibonacci_in_text(contents)
+        .into_iter()
+        .collect();
+    counts.sort_unstable();
+    if counts.is_empty() {
+        println!("No Fibonacci numbers in there");
+    } else {
+        let width = counts.last().unwrap().0.to_string().len().max(5);
+        println!("{:>width$} count", "value", width = width);
+        for (value, count) in counts {
+            println!("{:>width$} {:>5}", value, count, width = width);
         }
+    }
+    println!(
+        "{:.1}% of the numbers are Fibonacci numbers",
+        text::text_fibonacci_density(contents) * 100.0
+    );
+}
+
+// Read and write speed over a buffer of Fibonacci numbers, next to the
+// same over a buffer of just counting up
+fn memory_bandwidth(len: usize, reps: usize) {
+    println!(
+        "{} u128s ({:.1} MB), {} passes each way",
+        len,
+        (len * 16) as f64 / 1e6,
+        reps
+    );
+    let buffers = [
+        ("Fibonacci numbers", bandwidth::fibonacci_buffer(len)),
+        ("sequential integers", bandwidth::sequential_buffer(len)),
+    ];
+    for (name, mut buffer) in buffers {
+        let speed = bandwidth::measure_bandwidth(&mut buffer, reps);
+        println!(
+            "  {:19} read {:>8.2} GB/s  write {:>8.2} GB/s",
+            name, speed.read, speed.write
+        );
+    }
+}
+
+// The first 20 terms ready to paste into an OEIS search, under the
+// sequence they should find
+fn print_oeis(sequence: OeisSequence) {
+    println!("{} {}", sequence.id(), sequence.name());
+    println!("{}", sequence.url());
+    println!("{}", oeis::oeis_format(&sequence.terms(20)));
+}
+
+// Runs of Fibonacci numbers hiding in the bytes of a file
+fn scan_fib(data: &[u8]) {
+    let runs = scan::scan_for_fibonacci_subsequence(data);
+    if runs.is_empty() {
+        println!("No runs of 3 or more Fibonacci numbers in there");
+    }
+    for (start, end, values) in runs {
+        let values: Vec<String> = values.iter().map(u128::to_string).collect();
+        println!("bytes {}..{}: {}", start, end, values.join(", "));
+    }
+}
+
+// The digit sums of F(0) through F(n), along with the digital roots they
+// boil down to
+fn digit_sums(n: usize) {
+    for (k, sum) in digital_root::fib_digit_sums(n).into_iter().enumerate() {
+        println!(
+            "F({}) = {} has digit sum {} (digital root {})",
+            k,
+            better_dynamic_fib(k as u128),
+            sum,
+            digital_root::digital_root(k as u128)
+        );
+    }
+    println!(
+        "The digital roots repeat every {}",
+        digital_root::fib_digital_root_cycle_length()
+    );
+}
+
+// Split 1 to limit into the numbers with a Fibonacci factor (bigger
+// than 1) and the ones without
+fn divisibility_sieve(limit: u128) {
+    let sieve = sieve::fibonacci_divisibility_sieve(limit);
+    let (marked, unmarked): (Vec<usize>, Vec<usize>) = (1..sieve.len()).partition(|&k| sieve[k]);
+    let join = |numbers: &[usize]| {
+        numbers
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
     };
-    *memo.get(&fib_num).unwrap()
+    println!(
+        "{} numbers up to {} have a Fibonacci factor:",
+        marked.len(),
+        limit
+    );
+    println!("{}", join(&marked));
+    println!("and {} don't:", unmarked.len());
+    println!("{}", join(&unmarked));
 }
 
-// Similar idea as above, but uses a tuple instead of a HashMap (duh)
-// and constructs the chain of Fib numbers keeping ONLY the last 2
-// as it works it's way to the fib_num
-fn better_dynamic_fib(fib_num: u128) -> u128 {
-    let mut memo = (0, 1);
+// Each F(k + 1) / F(k) closing in on φ, and how far off it still is
+fn convergence_table(n: usize) {
+    let ratios = convergence::fib_gap_ratios(n);
+    let errors = convergence::fib_gap_percent_error(n);
+    println!("{:>4} {:>20} {:>12}", "k", "F(k+1) / F(k)", "% error");
+    for (k, (ratio, error)) in ratios.iter().zip(&errors).enumerate() {
+        println!("{:>4} {:>20.15} {:>12.3e}", k + 1, ratio, error);
+    }
+    println!("{:>4} {:>20.15}", "φ", convergence::PHI);
+}
 
-    match fib_num {
-        0 | 1 => fib_num,
-        _ => {
-            for _ in 2..=fib_num {
-                memo = (memo.1, memo.0 + memo.1)
-            }
-            memo.1
+// Show which squares light up, and what time that actually reads as
+// since the clock only does 5 minute steps
+fn fibonacci_clock(hours: u8, minutes: u8, render: bool) {
+    let colors = clock::fibonacci_clock_encode(hours, minutes);
+    if render {
+        println!("{}", clock::render_clock(hours, minutes));
+    } else {
+        for (color, side) in colors.iter().zip(&clock::SQUARES) {
+            println!("  {}x{} square: {:?}", side, side, color);
         }
     }
+    if let Some((hours, minutes)) = clock::fibonacci_clock_decode(&colors) {
+        println!("which reads as {}:{:02}", hours, minutes);
+    }
+}
+
+// Fibonacci code a few (Fibonacci!) numbers and see how small they get
+fn compress_demo() {
+    let numbers = [1, 2, 3, 5, 8, 13, 21, 34];
+    println!("\nFibonacci coding the sequence {:?}\n", numbers);
+    for &number in &numbers {
+        let bits = fibonacci_code::encode_sequence(&[number]);
+        println!(
+            "  {:>2} => {}",
+            number,
+            fibonacci_code::bits_to_string(&bits)
+        );
+    }
+
+    let bits = fibonacci_code::encode_sequence(&numbers);
+    println!(
+        "\nAll together that is {} bits: {}",
+        bits.len(),
+        fibonacci_code::bits_to_string(&bits)
+    );
+    println!(
+        "Compared to {} bits as plain u64s the compression ratio is {:.4}\n",
+        numbers.len() * 64,
+        fibonacci_code::compression_ratio(&numbers)
+    );
+}
+
+// Run the Fibonacci sequence mod M until it loops back around
+fn automaton(modulus: u128) {
+    let period = FibAutomaton::new(modulus).period();
+    println!(
+        "\nFibonacci numbers mod {} repeat every {} steps (the Pisano period)",
+        modulus, period
+    );
+
+    let shown = period.min(60) as usize;
+    let residues: Vec<String> = FibAutomaton::new(modulus)
+        .take(shown)
+        .map(|residue| residue.to_string())
+        .collect();
+    println!(
+        "  {}{}\n",
+        residues.join(" "),
+        if shown < period as usize { " ..." } else { "" }
+    );
+}
+
+// Pretend something keeps failing and show how long we'd wait each time
+fn demo_backoff() {
+    let mut backoff = FibBackoff::default();
+    let mut total = Duration::from_secs(0);
+    println!("\nFibonacci backoff starting at 1ms, up to 1 minute\n");
+    for retry in 1..=10 {
+        let delay = backoff.next_delay();
+        total += delay;
+        println!(
+            "  Retry {:>2} waits {:>6?} ({:?} in total)",
+            retry, delay, total
+        );
+    }
+    println!();
+}
+
+// Keep printing Fibonacci numbers until they won't fit any more, or
+// until somebody gets tired of waiting (a slow algorithm gets slow fast)
+// and hits Ctrl+C. The summary is printed from the signal handler, so
+// it still shows up even when stuck in the middle of a long calculation
+fn stream(kind: AlgorithmKind) {
+    let start = Instant::now();
+    // one past the highest n reached, so 0 can mean nothing yet
+    let reached = Arc::new(AtomicU64::new(0));
+
+    let handler_reached = Arc::clone(&reached);
+    ctrlc::set_handler(move || {
+        print_stream_summary(handler_reached.load(Ordering::SeqCst), start.elapsed());
+        process::exit(0);
+    })
+    .expect("couldn't set up the Ctrl+C handler");
+
+    for fib_num in 0..=MAX_FIB_INDEX_U128 {
+        let now = Instant::now();
+        let value = kind.compute(fib_num);
+        let elapsed = now.elapsed();
+        println!("F({}) = {} [{} ns]", fib_num, value, elapsed.as_nanos());
+        reached.store(fib_num as u64 + 1, Ordering::SeqCst);
+    }
+    println!(
+        "F({}) won't fit in a u128, so that's the end of the stream",
+        MAX_FIB_INDEX_U128 + 1
+    );
+    print_stream_summary(reached.load(Ordering::SeqCst), start.elapsed());
 }
 
-// cached crate memoizes input->output of this function for us so
-// we don't have to do any of it. We just do a simple recursive
-// backtrace and everything is sped up because of memoization in
-// the background
-#[cached(size = 200)]
-fn cached_fib(fib_num: u128) -> u128 {
-    if fib_num == 0 || fib_num == 1 {
-        return fib_num;
+fn print_stream_summary(reached: u64, elapsed: Duration) {
+    match reached {
+        0 => println!("\nDidn't reach any Fibonacci Numbers"),
+        _ => println!("\nHighest Fibonacci Number reached: F({})", reached - 1),
     }
-    cached_fib(fib_num - 1) + cached_fib(fib_num - 2)
+    println!("Total time: {} ns", elapsed.as_nanos());
 }
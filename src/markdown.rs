@@ -0,0 +1,46 @@
+use crate::prime::is_probably_prime;
+use crate::radix::fib_to_radix_prefixed;
+use crate::{better_dynamic_fib, MAX_FIB_INDEX_U128};
+
+#[test]
+fn test_fib_markdown_table() {
+    let table = fib_markdown_table(0, 10);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(
+        lines[0],
+        "| n | F(n) | F(n) in hex | is_prime | digit_count |"
+    );
+    assert_eq!(
+        lines[1],
+        "|--:|-----:|------------:|:--------:|------------:|"
+    );
+    assert_eq!(lines.len(), 2 + 11);
+    assert_eq!(lines[2], "| 0 | 0 | 0x0 | no | 1 |");
+    assert_eq!(lines[9], "| 7 | 13 | 0xd | yes | 2 |");
+    assert_eq!(lines[12], "| 10 | 55 | 0x37 | no | 2 |");
+
+    assert_eq!(fib_markdown_table(186, 186).lines().count(), 3);
+}
+
+// F(start) through F(end) as a Markdown table, with a few things worth
+// knowing about each one
+pub fn fib_markdown_table(start: u128, end: u128) -> String {
+    assert!(
+        start <= end && end <= MAX_FIB_INDEX_U128,
+        "start has to come before end, and end can't be past 186"
+    );
+    let mut table = String::from("| n | F(n) | F(n) in hex | is_prime | digit_count |\n");
+    table.push_str("|--:|-----:|------------:|:--------:|------------:|\n");
+    for n in start..=end {
+        let fib = better_dynamic_fib(n);
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            n,
+            fib,
+            fib_to_radix_prefixed(n, 16),
+            if is_probably_prime(fib) { "yes" } else { "no" },
+            fib.to_string().len()
+        ));
+    }
+    table
+}
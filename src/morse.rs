@@ -0,0 +1,62 @@
+use crate::{better_dynamic_fib, is_fibonacci};
+
+#[test]
+fn test_fib_to_morse() {
+    assert_eq!(fib_to_morse(7).as_deref(), Some(".---- ...--"));
+    assert_eq!(fib_to_morse(0).as_deref(), Some("-----"));
+    // F(25) = 75025 is the last one with five digits
+    assert_eq!(
+        fib_to_morse(25).as_deref(),
+        Some("--... ..... ----- ..--- .....")
+    );
+    assert_eq!(fib_to_morse(26), None);
+    assert_eq!(fib_to_morse(u32::MAX), None);
+}
+
+#[test]
+fn test_morse_to_fib() {
+    for k in 0..=25 {
+        let morse = fib_to_morse(k).unwrap();
+        assert_eq!(morse_to_fib(&morse), Some(better_dynamic_fib(k as u128)));
+    }
+    assert_eq!(morse_to_fib("  .----   ...--  "), Some(13));
+    // 14 isn't a Fibonacci number
+    assert_eq!(morse_to_fib(".---- ....-"), None);
+    assert_eq!(morse_to_fib(".-"), None);
+    assert_eq!(morse_to_fib(""), None);
+}
+
+const MORSE_DIGITS: [&str; 10] = [
+    "-----", ".----", "..---", "...--", "....-", ".....", "-....", "--...", "---..", "----.",
+];
+
+// Five decimal digits is as long as anyone wants to tap out
+pub const MAX_MORSE: u128 = 99_999;
+
+// The decimal digits of F(k) in Morse code, a space between each one
+pub fn fib_to_morse(k: u32) -> Option<String> {
+    // F(26) is already past MAX_MORSE
+    if k > 25 {
+        return None;
+    }
+    let value = better_dynamic_fib(k as u128);
+    if value > MAX_MORSE {
+        return None;
+    }
+    let digits: Vec<&str> = value
+        .to_string()
+        .bytes()
+        .map(|digit| MORSE_DIGITS[(digit - b'0') as usize])
+        .collect();
+    Some(digits.join(" "))
+}
+
+// Morse coded digits back to a number, as long as it's a Fibonacci one
+pub fn morse_to_fib(morse: &str) -> Option<u128> {
+    let mut value: Option<u128> = None;
+    for code in morse.split_whitespace() {
+        let digit = MORSE_DIGITS.iter().position(|&digit| digit == code)? as u128;
+        value = Some(value.unwrap_or(0).checked_mul(10)?.checked_add(digit)?);
+    }
+    value.filter(|&value| is_fibonacci(value))
+}
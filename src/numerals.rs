@@ -0,0 +1,163 @@
+use crate::{better_dynamic_fib, MAX_FIB_INDEX_U128};
+
+#[test]
+fn test_fib_to_chinese_numerals() {
+    let expected = [
+        "零",
+        "一",
+        "一",
+        "二",
+        "三",
+        "五",
+        "八",
+        "十三",
+        "二十一",
+        "三十四",
+        "五十五",
+        "八十九",
+        "一百四十四",
+    ];
+    for (n, &chinese) in expected.iter().enumerate() {
+        assert_eq!(fib_to_chinese_numerals(n as u128), chinese, "F({})", n);
+    }
+    assert_eq!(to_chinese(101), "一百零一");
+    assert_eq!(to_chinese(110), "一百一十");
+    assert_eq!(to_chinese(999), "九百九十九");
+}
+
+#[test]
+fn test_fib_to_roman_perso() {
+    assert_eq!(fib_to_roman_perso(0).as_deref(), Some("٠"));
+    assert_eq!(fib_to_roman_perso(7).as_deref(), Some("١٣"));
+    assert_eq!(fib_to_roman_perso(12).as_deref(), Some("١٤٤"));
+    assert!(fib_to_roman_perso(186).is_some());
+    assert_eq!(fib_to_roman_perso(187), None);
+}
+
+#[test]
+fn test_fib_subscript_superscript() {
+    assert_eq!(fib_subscript(10), "₅₅");
+    assert_eq!(fib_superscript(10), "⁵⁵");
+    assert_eq!(fib_subscript(0), "₀");
+    // 1, 2 and 3 are the odd ones out in the superscripts
+    assert_eq!(fib_superscript(16), "⁹⁸⁷");
+    assert_eq!(fib_superscript(20), "⁶⁷⁶⁵");
+    assert_eq!(fib_subscript(17), "₁₅₉₇");
+    assert_eq!(fib_superscript(18), "²⁵⁸⁴");
+    assert_eq!(fib_superscript(9), "³⁴");
+    assert_eq!(fib_subscript(186).chars().count(), 39);
+}
+
+#[test]
+fn test_fib_group_format() {
+    assert_eq!(fib_group_format(1_234_567_890), "123_456_78_9_0");
+    assert_eq!(fib_group_format(0), "0");
+    assert_eq!(fib_group_format(7), "7");
+    assert_eq!(fib_group_format(10), "1_0");
+    assert_eq!(fib_group_format(100), "1_0_0");
+    assert_eq!(fib_group_format(1_000), "10_0_0");
+    assert_eq!(fib_group_format(10_000), "1_00_0_0");
+    assert_eq!(fib_group_format(1_000_000), "100_00_0_0");
+    assert_eq!(fib_group_format(10_000_000), "1_000_00_0_0");
+    assert_eq!(
+        fib_group_format(u128::MAX),
+        "340282_3669209384634_63374607_43176_821_14_5_5"
+    );
+    for value in &[1u128, 55, 987_654_321, u128::MAX] {
+        assert_eq!(fib_group_format(*value).replace('_', ""), value.to_string());
+    }
+}
+
+const CHINESE_DIGITS: [char; 10] = ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+const PERSIAN_DIGITS: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+// F(12) = 144 is as far as the Chinese numerals go here
+pub const MAX_CHINESE_N: u128 = 12;
+
+// Below 1000 in Chinese numerals: a digit then 百 (hundred) or 十 (ten),
+// with 零 standing in for a missing ten in the middle (101 is 一百零一),
+// and the 一 in front of 十 left off when it comes first (13 is 十三)
+fn to_chinese(value: u128) -> String {
+    assert!(value < 1000, "only numbers below 1000 are supported");
+    let digit = |d: u128| CHINESE_DIGITS[d as usize];
+    let (hundreds, tens, ones) = (value / 100, value / 10 % 10, value % 10);
+    if value == 0 {
+        return digit(0).to_string();
+    }
+    let mut chinese = String::new();
+    if hundreds > 0 {
+        chinese.push(digit(hundreds));
+        chinese.push('百');
+    }
+    match tens {
+        0 if hundreds > 0 && ones > 0 => chinese.push(digit(0)),
+        0 => {}
+        1 if hundreds == 0 => chinese.push('十'),
+        _ => {
+            chinese.push(digit(tens));
+            chinese.push('十');
+        }
+    }
+    if ones > 0 {
+        chinese.push(digit(ones));
+    }
+    chinese
+}
+
+// F(n) in Chinese numerals, for n up to MAX_CHINESE_N
+pub fn fib_to_chinese_numerals(n: u128) -> String {
+    assert!(n <= MAX_CHINESE_N, "only F(0) through F(12) are supported");
+    to_chinese(better_dynamic_fib(n))
+}
+
+// F(n) in the Eastern Arabic digits used for Persian, which is the same
+// place value system as 0-9 with different glyphs, so anything that
+// fits in a u128 works
+pub fn fib_to_roman_perso(n: u128) -> Option<String> {
+    if n > MAX_FIB_INDEX_U128 {
+        return None;
+    }
+    Some(with_digits(better_dynamic_fib(n), &PERSIAN_DIGITS))
+}
+
+// The decimal digits of value, each swapped for the matching one of digits
+fn with_digits(value: u128, digits: &[char; 10]) -> String {
+    value
+        .to_string()
+        .bytes()
+        .map(|digit| digits[(digit - b'0') as usize])
+        .collect()
+}
+
+// F(n) in subscript digits, for things like x₅₅ in terminal output
+pub fn fib_subscript(n: u128) -> String {
+    assert!(n <= MAX_FIB_INDEX_U128, "F({}) doesn't fit in a u128", n);
+    with_digits(better_dynamic_fib(n), &SUBSCRIPT_DIGITS)
+}
+
+// And superscript, for x⁵⁵
+pub fn fib_superscript(n: u128) -> String {
+    assert!(n <= MAX_FIB_INDEX_U128, "F({}) doesn't fit in a u128", n);
+    with_digits(better_dynamic_fib(n), &SUPERSCRIPT_DIGITS)
+}
+
+// value with its digits split up by underscores, the groups going 1, 1,
+// 2, 3, 5, 8, ... digits long from the right instead of 3 at a time. The
+// leftmost group gets whatever is left over, so it can come up short
+pub fn fib_group_format(value: u128) -> String {
+    let digits = value.to_string();
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    for size in crate::iter::fibonacci_iter().skip(1) {
+        let start = end.saturating_sub(size as usize);
+        groups.push(&digits[start..end]);
+        end = start;
+        if end == 0 {
+            break;
+        }
+    }
+    groups.reverse();
+    groups.join("_")
+}
@@ -0,0 +1,68 @@
+use crate::iter::{fibonacci_iter, lucas_iter};
+use crate::recurrence::linear_recurrence;
+
+#[test]
+fn test_oeis_sequences() {
+    assert_eq!(
+        oeis_format(&OeisSequence::Fibonacci.terms(20)),
+        "0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610, 987, 1597, 2584, 4181"
+    );
+    assert_eq!(
+        OeisSequence::Lucas.terms(10),
+        [2, 1, 3, 4, 7, 11, 18, 29, 47, 76]
+    );
+    assert_eq!(
+        OeisSequence::Tribonacci.terms(10),
+        [0, 0, 1, 1, 2, 4, 7, 13, 24, 44]
+    );
+    assert_eq!(OeisSequence::Tribonacci.url(), "https://oeis.org/A000073");
+}
+
+// The sequences --oeis knows about
+#[derive(Clone, Copy)]
+pub enum OeisSequence {
+    Fibonacci,
+    Lucas,
+    Tribonacci,
+}
+
+impl OeisSequence {
+    pub fn id(self) -> &'static str {
+        match self {
+            OeisSequence::Fibonacci => "A000045",
+            OeisSequence::Lucas => "A000032",
+            OeisSequence::Tribonacci => "A000073",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            OeisSequence::Fibonacci => "Fibonacci numbers",
+            OeisSequence::Lucas => "Lucas numbers",
+            OeisSequence::Tribonacci => "Tribonacci numbers",
+        }
+    }
+
+    pub fn url(self) -> String {
+        format!("https://oeis.org/{}", self.id())
+    }
+
+    // The first count terms, starting where OEIS does (the tribonacci
+    // numbers there start 0, 0, 1)
+    pub fn terms(self, count: usize) -> Vec<u128> {
+        match self {
+            OeisSequence::Fibonacci => fibonacci_iter().take(count).collect(),
+            OeisSequence::Lucas => lucas_iter().take(count).collect(),
+            OeisSequence::Tribonacci => (0..count)
+                .map(|n| linear_recurrence(&[1, 1, 1], &[0, 0, 1], n) as u128)
+                .collect(),
+        }
+    }
+}
+
+// Comma and space between terms, the way OEIS shows them and takes them
+// in its search box
+pub fn oeis_format(terms: &[u128]) -> String {
+    let terms: Vec<String> = terms.iter().map(u128::to_string).collect();
+    terms.join(", ")
+}
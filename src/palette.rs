@@ -0,0 +1,64 @@
+use crate::convergence::PHI;
+
+#[test]
+fn test_fib_color_palette() {
+    let palette = fib_color_palette(6);
+    assert_eq!(palette.len(), 6);
+    assert_eq!(palette[0], [242, 85, 85]);
+    let hues: Vec<f64> = (0..6).map(golden_hue).collect();
+    for (i, a) in hues.iter().enumerate() {
+        for b in &hues[i + 1..] {
+            let distance = (a - b).abs().min(1.0 - (a - b).abs());
+            // the closest two are 0 and 5 / φ, 1 / φ^5 = 0.0902 apart
+            assert!(distance > 0.09, "{} and {}", a, b);
+        }
+    }
+    assert!(fib_color_palette(0).is_empty());
+    assert_eq!(fib_color_palette(255).len(), 255);
+}
+
+#[test]
+fn test_hsv_to_rgb() {
+    assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), [255, 0, 0]);
+    assert_eq!(hsv_to_rgb(1.0 / 3.0, 1.0, 1.0), [0, 255, 0]);
+    assert_eq!(hsv_to_rgb(2.0 / 3.0, 1.0, 1.0), [0, 0, 255]);
+    assert_eq!(hsv_to_rgb(0.5, 0.0, 0.5), [128, 128, 128]);
+}
+
+// Less than full saturation and brightness, so the colors aren't
+// blinding
+const SATURATION: f64 = 0.65;
+const VALUE: f64 = 0.95;
+
+// How far round the color wheel color k goes: k / φ, keeping just the
+// fraction. That's the golden angle sunflower seeds are spaced by, and
+// a new color always lands in one of the biggest gaps left, so however
+// many there are they stay spread out
+fn golden_hue(k: u8) -> f64 {
+    (k as f64 / PHI).fract()
+}
+
+// n colors, each a golden angle further round the wheel than the last
+pub fn fib_color_palette(n: u8) -> Vec<[u8; 3]> {
+    (0..n)
+        .map(|k| hsv_to_rgb(golden_hue(k), SATURATION, VALUE))
+        .collect()
+}
+
+// hue, saturation and value all from 0 to 1
+pub fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> [u8; 3] {
+    let sector = (hue * 6.0).rem_euclid(6.0);
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+    let channel = |c: f64| ((c + m) * 255.0).round() as u8;
+    [channel(r), channel(g), channel(b)]
+}
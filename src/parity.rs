@@ -0,0 +1,27 @@
+#[test]
+fn test_parity() {
+    for n in 0..=93 {
+        assert_eq!(
+            fib_is_even(n),
+            crate::better_dynamic_fib(n).is_multiple_of(2)
+        );
+        assert_eq!(fib_parity(n) as u128, crate::better_dynamic_fib(n) % 2);
+    }
+}
+
+// Odd + odd is even, then even + odd and odd + even are both odd, so
+// the parity goes even, odd, odd, even, odd, odd... forever and F(n) is
+// even exactly when 3 divides n. No need to actually compute F(n), which
+// is handy when n is way past what fits in a u128
+pub fn fib_is_even(fib_num: u128) -> bool {
+    fib_num.is_multiple_of(3)
+}
+
+// 0 for even, 1 for odd (so the same as F(n) % 2)
+pub fn fib_parity(fib_num: u128) -> u8 {
+    if fib_is_even(fib_num) {
+        0
+    } else {
+        1
+    }
+}
@@ -0,0 +1,47 @@
+use libloading::{Library, Symbol};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+// What a plugin has to export, both with #[no_mangle] and extern "C":
+//   compute(n: u128) -> u128, which works out F(n)
+//   name() -> *const c_char, a nul terminated name for the race
+//   printout that has to stay valid as long as the library is loaded
+pub type ComputeFn = extern "C" fn(u128) -> u128;
+pub type NameFn = extern "C" fn() -> *const c_char;
+
+// A shared library (.so, .dylib or .dll) raced next to the built in
+// algorithms. The library has to stay loaded for as long as compute
+// might get called, so it's kept here even though nothing reads it
+pub struct FibPlugin {
+    name: String,
+    compute: ComputeFn,
+    _library: Library,
+}
+
+impl FibPlugin {
+    pub fn load(path: &str) -> Result<FibPlugin, libloading::Error> {
+        // loading runs the library's initializers and the symbols are
+        // taken on trust to have the signatures above, which is what
+        // makes this unsafe. Pointing --plugin at something is
+        // agreeing to both
+        unsafe {
+            let library = Library::new(path)?;
+            let compute: Symbol<ComputeFn> = library.get(b"compute")?;
+            let name: Symbol<NameFn> = library.get(b"name")?;
+            let name = CStr::from_ptr(name()).to_string_lossy().into_owned();
+            Ok(FibPlugin {
+                name,
+                compute: *compute,
+                _library: library,
+            })
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn compute(&self, fib_num: u128) -> u128 {
+        (self.compute)(fib_num)
+    }
+}
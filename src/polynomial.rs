@@ -0,0 +1,34 @@
+#[test]
+fn test_fibonacci_polynomial() {
+    assert_eq!(fibonacci_polynomial(5, 1.0), 5.0);
+    assert_eq!(fibonacci_polynomial(3, 2.0), 5.0);
+    assert_eq!(fibonacci_polynomial(0, 3.0), 0.0);
+    assert_eq!(fibonacci_polynomial(1, 3.0), 1.0);
+    assert_eq!(fibonacci_polynomial(2, 3.0), 3.0);
+    // x^4 + 3x^2 + 1
+    assert_eq!(fibonacci_polynomial(5, 2.0), 29.0);
+    for n in 0..=70 {
+        assert_eq!(
+            fibonacci_polynomial(n, 1.0),
+            crate::better_dynamic_fib(n as u128) as f64
+        );
+    }
+}
+
+// The Fibonacci polynomials, f_n(x) = x f_(n-1)(x) + f_(n-2)(x) starting
+// from f_0 = 0 and f_1 = 1 (so f_2 = x, f_3 = x^2 + 1, ...). At x = 1
+// that's just the Fibonacci recurrence again, so f_n(1) = F(n), and at
+// x = 2 it's the Pell numbers. Same keep-the-last-two loop as
+// better_dynamic_fib
+pub fn fibonacci_polynomial(n: usize, x: f64) -> f64 {
+    let mut memo = (0.0, 1.0);
+    match n {
+        0 => 0.0,
+        _ => {
+            for _ in 2..=n {
+                memo = (memo.1, x * memo.1 + memo.0);
+            }
+            memo.1
+        }
+    }
+}
@@ -0,0 +1,63 @@
+#[test]
+fn test_predict_next_fibonacci_like() {
+    let fibs: Vec<f64> = crate::iter::fibonacci_iter()
+        .take(40)
+        .map(|fib| fib as f64)
+        .collect();
+    for start in 5..20 {
+        for len in 4..=15 {
+            let predicted = predict_next_fibonacci_like(&fibs[start..start + len]);
+            let actual = fibs[start + len];
+            let error = (predicted - actual).abs() / actual;
+            assert!(error < 0.01, "F({}) off by {}", start + len, error);
+        }
+    }
+    // the very start isn't quite on the line yet
+    let error = (predict_next_fibonacci_like(&fibs[1..6]) - 8.0).abs() / 8.0;
+    assert!(error > 0.05);
+
+    // the same thing, but every value is up to 3% off
+    let noise = [1.03, 0.98, 1.01, 0.97, 1.02, 0.99, 1.03, 0.97, 1.0, 1.02];
+    let noisy: Vec<f64> = fibs[11..21]
+        .iter()
+        .zip(&noise)
+        .map(|(fib, noise)| fib * noise)
+        .collect();
+    let predicted = predict_next_fibonacci_like(&noisy);
+    assert!((predicted - fibs[21]).abs() / fibs[21] < 0.05);
+
+    // a Lucas-like start still grows by φ every step
+    let predicted = predict_next_fibonacci_like(&[76.0, 123.0, 199.0, 322.0]);
+    assert!((predicted - 521.0).abs() / 521.0 < 0.01);
+}
+
+// Anything that grows like the Fibonacci numbers is close to c φ^i for
+// some constant c, so log(series[i]) is a straight line in i with a
+// slope around log(φ). Fit that line with least squares and follow it
+// one step past the end. Real Fibonacci numbers are only that close to
+// a line from about F(5) on, before that the (1 - φ)^i part of Binet's
+// formula still throws them off by more than a percent. Every value has
+// to be positive (there's no log otherwise) and it takes at least two
+// to draw a line
+pub fn predict_next_fibonacci_like(series: &[f64]) -> f64 {
+    assert!(
+        series.len() >= 2,
+        "it takes at least two values to see a trend"
+    );
+    assert!(
+        series.iter().all(|&value| value > 0.0),
+        "every value has to be positive"
+    );
+    let n = series.len() as f64;
+    let logs: Vec<f64> = series.iter().map(|value| value.ln()).collect();
+    let mean_i = (n - 1.0) / 2.0;
+    let mean_log = logs.iter().sum::<f64>() / n;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for (i, log) in logs.iter().enumerate() {
+        let di = i as f64 - mean_i;
+        covariance += di * (log - mean_log);
+        variance += di * di;
+    }
+    let slope = covariance / variance;
+    (mean_log + slope * (n - mean_i)).exp()
+}
@@ -0,0 +1,170 @@
+use crate::better_dynamic_fib;
+use crate::ladder::binary_pow;
+use num_bigint::BigUint;
+use std::mem;
+
+#[test]
+fn test_is_probably_prime() {
+    for n in 0..10_000u128 {
+        assert_eq!(is_probably_prime(n), primal::is_prime(n as u64), "{}", n);
+    }
+    // Carmichael numbers fool a plain Fermat test, but not this one
+    for &carmichael in &[561, 1105, 1729, 2465, 2821, 6601, 8911] {
+        assert!(!is_probably_prime(carmichael));
+    }
+    assert!(is_probably_prime((1 << 61) - 1));
+    assert!(is_probably_prime(u128::MAX - 158));
+    assert!(!is_probably_prime(u128::MAX));
+}
+
+#[test]
+fn test_fib_is_probably_prime() {
+    let prime_indices: Vec<u128> = (0..=50).filter(|&n| fib_is_probably_prime(n)).collect();
+    assert_eq!(prime_indices, [3, 4, 5, 7, 11, 13, 17, 23, 29, 43, 47]);
+}
+
+#[test]
+fn test_next_prime_after_fib() {
+    for n in 0..=15 {
+        let fib = better_dynamic_fib(n);
+        let prime = next_prime_after_fib(n);
+        assert!(prime >= fib && primal::is_prime(prime as u64));
+        assert!((fib..prime).all(|k| !primal::is_prime(k as u64)));
+    }
+    assert_eq!(next_prime_after_fib(0), 2);
+    assert_eq!(next_prime_after_fib(6), 11);
+    // already prime
+    assert_eq!(next_prime_after_fib(7), 13);
+}
+
+#[test]
+fn test_is_probably_prime_big() {
+    for n in 0..2_000u128 {
+        assert_eq!(
+            is_probably_prime_big(&BigUint::from(n)),
+            is_probably_prime(n),
+            "{}",
+            n
+        );
+    }
+    // F(359) is prime, F(361) = F(19) × ... isn't
+    assert!(is_probably_prime_big(&crate::bigint::bigint_fib(359)));
+    assert!(!is_probably_prime_big(&crate::bigint::bigint_fib(361)));
+}
+
+#[test]
+fn test_fibonacci_prime_indices() {
+    assert_eq!(
+        fibonacci_prime_indices(30),
+        [3, 4, 5, 7, 11, 13, 17, 23, 29]
+    );
+    assert_eq!(
+        fibonacci_prime_indices(200),
+        [3, 4, 5, 7, 11, 13, 17, 23, 29, 43, 47, 83, 131, 137]
+    );
+    assert!(fibonacci_prime_indices(2).is_empty());
+}
+
+// Enough bases that no composite below 3.3 × 10^24 gets past all of
+// them. Bigger than that it's only probably prime, but a composite
+// would have to fool every one of the twenty
+const BASES: [u128; 20] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+];
+
+// Miller-Rabin: write n - 1 as d × 2^s, then for a prime n every base
+// a has a^d ≡ 1, or a^(d × 2^r) ≡ -1 for some r below s. Any base
+// where neither happens proves n is composite
+pub fn is_probably_prime(n: u128) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &base in &BASES {
+        if n.is_multiple_of(base) {
+            return n == base;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    BASES.iter().all(|&base| {
+        let mut x = binary_pow(base, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = binary_pow(x, 2, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+// Whether F(n) is (probably) prime. Apart from F(4) = 3, n has to be
+// prime for F(n) to be, but that isn't enough: F(19) = 4181 = 37 × 113
+pub fn fib_is_probably_prime(n: u128) -> bool {
+    is_probably_prime(better_dynamic_fib(n))
+}
+
+// The smallest prime that's at least F(n), so F(n) itself when it's
+// prime already. Primes around F(186) are only about 88 apart, so there
+// is always one before u128 runs out
+pub fn next_prime_after_fib(n: u128) -> u128 {
+    let mut candidate = better_dynamic_fib(n);
+    while !is_probably_prime(candidate) {
+        candidate += 1;
+    }
+    candidate
+}
+
+// The same test for numbers too big for a u128, like F(n) past n = 186
+pub fn is_probably_prime_big(n: &BigUint) -> bool {
+    let one = BigUint::from(1u8);
+    if *n <= one {
+        return false;
+    }
+    for &base in &BASES {
+        let base = BigUint::from(base);
+        if (n % &base) == BigUint::from(0u8) {
+            return *n == base;
+        }
+    }
+    let n_minus_one = n - &one;
+    let s = n_minus_one.trailing_zeros().unwrap();
+    let d = &n_minus_one >> s;
+    BASES.iter().all(|&base| {
+        let mut x = BigUint::from(base).modpow(&d, n);
+        if x == one || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = x.modpow(&BigUint::from(2u8), n);
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+// How far --prime-indices will go, since each test gets slower as F(k)
+// grows (F(2000) is over 400 digits)
+pub const MAX_PRIME_INDEX: u32 = 2000;
+
+// Every k up to max_index where F(k) is (probably) prime. F(k) divides
+// F(mk), so a composite k means a composite F(k), except k = 4 where the
+// factor is F(2) = 1. That leaves 4 and the primes to actually test,
+// walking the sequence along in a BigUint to get each F(k)
+pub fn fibonacci_prime_indices(max_index: u32) -> Vec<u32> {
+    let mut indices = Vec::new();
+    let (mut fib, mut next) = (BigUint::from(0u8), BigUint::from(1u8));
+    for k in 0..=max_index {
+        if (k == 4 || primal::is_prime(k as u64)) && is_probably_prime_big(&fib) {
+            indices.push(k);
+        }
+        let after = &fib + &next;
+        fib = mem::replace(&mut next, after);
+    }
+    indices
+}
@@ -0,0 +1,55 @@
+use crate::better_dynamic_fib;
+
+#[test]
+fn test_fib_to_alphanumeric() {
+    assert_eq!(fib_to_alphanumeric(10), "55");
+    assert_eq!(fib_to_alphanumeric(0), "0");
+    for n in 0..=crate::MAX_FIB_INDEX_U128 {
+        assert!(fib_to_alphanumeric(n).chars().all(is_qr_alphanumeric));
+    }
+    assert!(is_qr_alphanumeric('$') && is_qr_alphanumeric(' '));
+    assert!(!is_qr_alphanumeric('a') && !is_qr_alphanumeric('#'));
+}
+
+#[test]
+fn test_fib_qr_metadata() {
+    assert_eq!(fib_qr_metadata(10), (2, 1));
+    // 25 characters is as many as version 1 holds
+    assert_eq!(fib_qr_metadata(119), (25, 1));
+    assert_eq!(fib_qr_metadata(124), (26, 2));
+    assert_eq!(fib_qr_metadata(186), (39, 2));
+}
+
+// The 45 characters QR alphanumeric mode can hold
+const ALPHANUMERIC: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+// How many 8 bit data codewords versions 1 through 9 have room for at
+// the lowest error correction level (L). F(186) is only 39 digits, so
+// it never gets anywhere near version 9
+const DATA_CODEWORDS: [usize; 9] = [19, 34, 55, 80, 108, 136, 156, 194, 232];
+
+pub fn is_qr_alphanumeric(c: char) -> bool {
+    ALPHANUMERIC.contains(c)
+}
+
+// The decimal digits of F(n), which are already all QR alphanumeric
+// characters
+pub fn fib_to_alphanumeric(n: u128) -> String {
+    better_dynamic_fib(n).to_string()
+}
+
+// (characters, smallest QR version that fits them) for F(n) in
+// alphanumeric mode at level L. The data needs a 4 bit mode, a 9 bit
+// character count (for versions 1 to 9), 11 bits for each pair of
+// characters and 6 for one left over, and it has to fit in the
+// version's data codewords
+pub fn fib_qr_metadata(n: u128) -> (usize, usize) {
+    let count = fib_to_alphanumeric(n).len();
+    let bits = 4 + 9 + 11 * (count / 2) + 6 * (count % 2);
+    let version = DATA_CODEWORDS
+        .iter()
+        .position(|&codewords| bits <= codewords * 8)
+        .expect("F(n) is never too big for version 9")
+        + 1;
+    (count, version)
+}
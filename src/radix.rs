@@ -0,0 +1,154 @@
+use crate::{better_dynamic_fib, MAX_FIB_INDEX_U128};
+
+#[test]
+fn test_fib_to_radix() {
+    assert_eq!(fib_to_radix(10, 16), "37");
+    assert_eq!(fib_to_radix(10, 10), "55");
+    assert_eq!(fib_to_radix(0, 2), "0");
+    assert_eq!(fib_to_radix(6, 2), "1000");
+    assert_eq!(fib_to_radix(7, 2), "1101");
+    assert_eq!(fib_to_radix(12, 8), "220");
+    assert_eq!(fib_to_radix(15, 36), "gy");
+    assert_eq!(
+        fib_to_radix(186, 16),
+        format!("{:x}", better_dynamic_fib(186))
+    );
+    assert_eq!(fib_to_radix_prefixed(10, 16), "0x37");
+    assert_eq!(fib_to_radix_prefixed(7, 2), "0b1101");
+    assert_eq!(fib_to_radix_prefixed(15, 36), "gy");
+}
+
+#[test]
+fn test_fib_multibase() {
+    assert_eq!(fib_multibase(10, &[2, 16]), vec!["110111", "37"]);
+    assert_eq!(
+        fib_multibase(20, &FULL_MULTIBASE),
+        ["1101001101101", "15155", "6765", "1a6d", "57x"]
+    );
+    assert!(fib_multibase(10, &[]).is_empty());
+}
+
+#[test]
+fn test_fib_width() {
+    assert_eq!(fib_binary_width(10, 8).as_deref(), Some("00110111"));
+    assert_eq!(fib_binary_width(10, 6).as_deref(), Some("110111"));
+    assert_eq!(fib_binary_width(10, 5), None);
+    assert_eq!(fib_binary_width(0, 0).as_deref(), Some(""));
+    assert_eq!(fib_binary_width(186, 128).map(|bits| bits.len()), Some(128));
+    assert_eq!(fib_binary_width(186, 200).map(|bits| bits.len()), Some(200));
+
+    // 55 needs 6 bits, more than the one hex digit 4 bits make
+    assert_eq!(fib_hex_width(10, 4), None);
+    assert_eq!(fib_hex_width(10, 8).as_deref(), Some("37"));
+    assert_eq!(fib_hex_width(10, 16).as_deref(), Some("0037"));
+    // a width that isn't a whole number of hex digits still gets the
+    // digits to hold it, but the value has to fit in the bits
+    assert_eq!(fib_hex_width(10, 6).as_deref(), Some("37"));
+    assert_eq!(fib_hex_width(11, 6), None);
+}
+
+#[test]
+fn test_fib_popcount() {
+    assert_eq!(
+        fib_popcount_sequence(16),
+        [0, 1, 1, 1, 2, 2, 1, 3, 3, 2, 5, 4, 2, 5, 6, 4]
+    );
+    assert_eq!(fib_popcount(6), 1);
+    for (n, count) in fib_popcount_sequence(187).into_iter().enumerate() {
+        assert_eq!(
+            count,
+            fib_to_radix(n as u128, 2).matches('1').count() as u32
+        );
+    }
+}
+
+// F(n) written out in any base from 2 to 36, using 0-9 then a-z
+pub fn fib_to_radix(n: u128, radix: u32) -> String {
+    assert!((2..=36).contains(&radix), "radix has to be from 2 to 36");
+    assert!(n <= MAX_FIB_INDEX_U128, "F({}) doesn't fit in a u128", n);
+
+    let mut value = better_dynamic_fib(n);
+    if value == 0 {
+        return "0".to_string();
+    }
+    let mut digits = Vec::new();
+    while value > 0 {
+        let digit = (value % radix as u128) as u32;
+        digits.push(std::char::from_digit(digit, radix).unwrap());
+        value /= radix as u128;
+    }
+    digits.iter().rev().collect()
+}
+
+// Binary, octal, decimal, hex and base 36, for --full-multibase
+pub const FULL_MULTIBASE: [u32; 5] = [2, 8, 10, 16, 36];
+
+// fib_to_radix for each of the bases, in the same order
+pub fn fib_multibase(n: u128, bases: &[u32]) -> Vec<String> {
+    bases.iter().map(|&radix| fib_to_radix(n, radix)).collect()
+}
+
+// The usual 0b, 0o and 0x in front for the bases that have one
+pub fn radix_prefix(radix: u32) -> &'static str {
+    match radix {
+        2 => "0b",
+        8 => "0o",
+        16 => "0x",
+        _ => "",
+    }
+}
+
+pub fn fib_to_radix_prefixed(n: u128, radix: u32) -> String {
+    format!("{}{}", radix_prefix(radix), fib_to_radix(n, radix))
+}
+
+// Whether F(n) fits in an unsigned field width bits wide
+fn fits_in(value: u128, width: u32) -> bool {
+    width >= 128 || value >> width == 0
+}
+
+// F(n) zero padded to exactly width bits, for a fixed width field, or
+// None if it doesn't fit
+pub fn fib_binary_width(n: u128, width: u32) -> Option<String> {
+    assert!(n <= MAX_FIB_INDEX_U128, "F({}) doesn't fit in a u128", n);
+    let value = better_dynamic_fib(n);
+    if !fits_in(value, width) {
+        return None;
+    }
+    if width == 0 {
+        return Some(String::new());
+    }
+    Some(format!("{:0width$b}", value, width = width as usize))
+}
+
+// The same, but in hex. width is still in bits, so the field is
+// width / 4 hex digits (rounded up)
+pub fn fib_hex_width(n: u128, width: u32) -> Option<String> {
+    assert!(n <= MAX_FIB_INDEX_U128, "F({}) doesn't fit in a u128", n);
+    let value = better_dynamic_fib(n);
+    if !fits_in(value, width) {
+        return None;
+    }
+    let digits = width.div_ceil(4) as usize;
+    if digits == 0 {
+        return Some(String::new());
+    }
+    Some(format!("{:0digits$x}", value, digits = digits))
+}
+
+// How many 1 bits F(n) has. It isn't just growing with n (F(6) = 8 has
+// one), but it does tend to infinity: Stewart showed in 1980 that the
+// number of nonzero digits of a sequence like this one, in any base,
+// eventually stays above about log n / log log n
+pub fn fib_popcount(n: u128) -> u32 {
+    assert!(n <= MAX_FIB_INDEX_U128, "F({}) doesn't fit in a u128", n);
+    better_dynamic_fib(n).count_ones()
+}
+
+// fib_popcount for F(0) through F(len - 1)
+pub fn fib_popcount_sequence(len: usize) -> Vec<u32> {
+    crate::iter::fibonacci_iter()
+        .take(len)
+        .map(u128::count_ones)
+        .collect()
+}
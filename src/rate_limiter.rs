@@ -0,0 +1,98 @@
+use crate::iter::FibIter;
+use std::convert::TryFrom;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_fib_rate_limiter() {
+    let start = Instant::now();
+    let window = Duration::from_secs(1);
+    let mut limiter = FibRateLimiter::starting_at(window, start);
+
+    // hammer it with 10 requests a window, and count what gets through
+    let mut allowed = Vec::new();
+    for w in 0..10 {
+        let now = start + window * w + Duration::from_millis(500);
+        allowed.push((0..10).filter(|_| limiter.allow_at(now)).count());
+    }
+    assert_eq!(allowed, [1, 1, 2, 3, 5, 8, 10, 10, 10, 10]);
+
+    // within a window it's all the allowed ones first, then all denies
+    let mut limiter = FibRateLimiter::starting_at(window, start);
+    let now = start + window * 4;
+    for w in 1..4 {
+        limiter.allow_at(start + window * w);
+    }
+    let pattern: Vec<bool> = (0..6).map(|_| limiter.allow_at(now)).collect();
+    assert_eq!(pattern, [true, true, true, true, true, false]);
+    assert_eq!(limiter.limit(), 5);
+}
+
+// A rate limiter whose allowance grows every window: 1 request in the
+// first window, then 1, 2, 3, 5, 8... Once a window's allowance is used
+// up everything is denied until the next window starts, and the count
+// starts over there. Since F(1) + ... + F(k) = F(k + 2) - 1, a client
+// that sticks around for k windows has been let through F(k + 2) - 1
+// times in all, so trust ramps up by φ a window instead of jumping
+// straight to some big fixed limit (or doubling, which gets there a lot
+// faster)
+pub struct FibRateLimiter {
+    allowed_counts: FibIter,
+    window: Duration,
+    last_reset: Instant,
+    current_count: u64,
+    limit: u64,
+}
+
+impl FibRateLimiter {
+    pub fn new(window: Duration) -> FibRateLimiter {
+        FibRateLimiter::starting_at(window, Instant::now())
+    }
+
+    // For when the first window shouldn't start right now
+    pub fn starting_at(window: Duration, start: Instant) -> FibRateLimiter {
+        let mut allowed_counts = FibIter::new();
+        allowed_counts.next(); // F(0) would mean nobody ever gets in
+        let mut limiter = FibRateLimiter {
+            allowed_counts,
+            window,
+            last_reset: start,
+            current_count: 0,
+            limit: 0,
+        };
+        limiter.next_limit();
+        limiter
+    }
+
+    // Past the last Fibonacci number that fits just keeps that limit
+    fn next_limit(&mut self) {
+        if let Some(fib) = self.allowed_counts.next() {
+            self.limit = u64::try_from(fib).unwrap_or(u64::MAX);
+        }
+    }
+
+    // How many requests the current window lets through
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    pub fn allow(&mut self) -> bool {
+        self.allow_at(Instant::now())
+    }
+
+    // allow as if it were now, mostly so tests don't have to sleep. A
+    // new window starts at the first request after the last one is over
+    // (no matter how long ago that was) and moves the limit up one step
+    pub fn allow_at(&mut self, now: Instant) -> bool {
+        if now.duration_since(self.last_reset) >= self.window {
+            self.last_reset = now;
+            self.current_count = 0;
+            self.next_limit();
+        }
+        if self.current_count < self.limit {
+            self.current_count += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
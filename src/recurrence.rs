@@ -0,0 +1,70 @@
+#[test]
+fn test_linear_recurrence() {
+    assert_eq!(linear_recurrence(&[1, 1], &[0, 1], 20), 6765);
+    assert_eq!(linear_recurrence(&[1, 2], &[0, 1], 5), 11);
+    // Lucas, Pell and tribonacci
+    assert_eq!(linear_recurrence(&[1, 1], &[2, 1], 10), 123);
+    assert_eq!(linear_recurrence(&[2, 1], &[0, 1], 6), 70);
+    assert_eq!(linear_recurrence(&[1, 1, 1], &[0, 0, 1], 10), 81);
+    // the initial values come straight back
+    assert_eq!(linear_recurrence(&[1, 1, 1], &[4, 5, 6], 1), 5);
+    // negative coefficients: a(n) = a(n - 1) - a(n - 2) goes round in 6
+    let terms: Vec<i64> = (0..7)
+        .map(|n| linear_recurrence(&[1, -1], &[0, 1], n))
+        .collect();
+    assert_eq!(terms, [0, 1, 1, 0, -1, -1, 0]);
+
+    for n in 0..=92 {
+        assert_eq!(
+            linear_recurrence(&[1, 1], &[0, 1], n) as u128,
+            crate::better_dynamic_fib(n as u128)
+        );
+    }
+    assert_eq!(checked_linear_recurrence(&[1, 1], &[0, 1], 93), None);
+}
+
+#[test]
+#[should_panic(expected = "a coefficient for each initial value")]
+fn test_linear_recurrence_mismatch() {
+    linear_recurrence(&[1, 1], &[0, 1, 1], 5);
+}
+
+// The nth term of a(n) = c[0] a(n - 1) + c[1] a(n - 2) + ... + c[k - 1]
+// a(n - k), starting from a(0) through a(k - 1) being initial_values.
+// better_dynamic_fib is the [1, 1] and [0, 1] one. None if a term along
+// the way doesn't fit in an i64
+pub fn checked_linear_recurrence(
+    coefficients: &[i64],
+    initial_values: &[i64],
+    n: usize,
+) -> Option<i64> {
+    assert_eq!(
+        coefficients.len(),
+        initial_values.len(),
+        "there has to be a coefficient for each initial value"
+    );
+    assert!(
+        !coefficients.is_empty(),
+        "there has to be at least one term"
+    );
+    if n < initial_values.len() {
+        return Some(initial_values[n]);
+    }
+
+    // only the last k terms are ever needed, oldest first
+    let mut terms = initial_values.to_vec();
+    for _ in initial_values.len()..=n {
+        let mut next: i64 = 0;
+        for (coefficient, term) in coefficients.iter().zip(terms.iter().rev()) {
+            next = next.checked_add(coefficient.checked_mul(*term)?)?;
+        }
+        terms.remove(0);
+        terms.push(next);
+    }
+    terms.last().copied()
+}
+
+pub fn linear_recurrence(coefficients: &[i64], initial_values: &[i64], n: usize) -> i64 {
+    checked_linear_recurrence(coefficients, initial_values, n)
+        .unwrap_or_else(|| panic!("term {} doesn't fit in an i64", n))
+}
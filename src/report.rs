@@ -0,0 +1,340 @@
+use std::time::Duration;
+
+#[test]
+fn test_humanize_duration() {
+    assert_eq!(humanize_duration(500), "500 ns");
+    assert_eq!(humanize_duration(1_500), "1.50 µs");
+    assert_eq!(humanize_duration(1_500_000), "1.50 ms");
+    assert_eq!(humanize_duration(12_345_678), "12.35 ms");
+    assert_eq!(humanize_duration(2_000_000_000), "2.00 s");
+    assert_eq!(humanize_duration(90_000_000_000), "90.00 s");
+    // these would round up to 1000.00 of the smaller unit
+    assert_eq!(humanize_duration(999_994), "999.99 µs");
+    assert_eq!(humanize_duration(999_995), "1.00 ms");
+    assert_eq!(humanize_duration(999_999), "1.00 ms");
+    assert_eq!(humanize_duration(999_994_999), "999.99 ms");
+    assert_eq!(humanize_duration(999_999_999), "1.00 s");
+}
+
+#[test]
+fn test_json_output() {
+    let mut report = BenchmarkReport::default();
+    report.record("a \"quoted\" algorithm", 20, Duration::from_nanos(1234));
+    report.start_round();
+    report.record("cached function", 20, Duration::from_nanos(5));
+    assert_eq!(
+        report.to_json(),
+        "[\n  {\"round\": 1, \"algorithm\": \"a \\\"quoted\\\" algorithm\", \"n\": 20, \"elapsed_ns\": 1234},\n  {\"round\": 2, \"algorithm\": \"cached function\", \"n\": 20, \"elapsed_ns\": 5}\n]"
+    );
+    assert_eq!(BenchmarkReport::default().to_json(), "[]");
+}
+
+#[test]
+fn test_scale_plot() {
+    let mut report = BenchmarkReport::default();
+    report.record("slow", 20, Duration::from_nanos(5200));
+    report.record("fast", 20, Duration::from_nanos(1000));
+    let plot = report.to_scale_plot(40);
+    assert_eq!(plot.len(), 2);
+    assert!(plot[0].starts_with("slow "));
+    assert!(plot[0].ends_with(" 5.2×"));
+    assert!(plot[1].ends_with(" 1.0×"));
+    let bar_len = |line: &str| line.matches('█').count();
+    assert_eq!(bar_len(&plot[0]), 30);
+    assert_eq!(bar_len(&plot[1]), 6);
+    assert!(plot.iter().all(|line| line.chars().count() <= 40));
+}
+
+#[test]
+fn test_sparklines() {
+    let mut report = BenchmarkReport::default();
+    for &(slow, fast) in &[(800, 50), (100, 50), (400, 50), (100, 50)] {
+        report.start_round();
+        report.record("slow", 20, Duration::from_nanos(slow));
+        report.record("fast", 20, Duration::from_nanos(fast));
+    }
+    let lines = report.to_sparklines();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("slow █▁▄▁ "));
+    assert!(lines[0].ends_with(" 100 ns to 800 ns"));
+    // nothing changed, so it's flat along the bottom
+    assert!(lines[1].starts_with("fast ▁▁▁▁ "));
+    assert!(BenchmarkReport::default().to_sparklines().is_empty());
+}
+
+#[test]
+fn test_round_trends() {
+    let mut report = BenchmarkReport::default();
+    let tuple = "dynamic programming with memoization via tuple";
+    for &(tuple_ns, noisy_ns) in &[(400, 800), (200, 200), (200, 600), (100, 150)] {
+        report.start_round();
+        report.record(tuple, 20, Duration::from_nanos(tuple_ns));
+        report.record("noisy", 20, Duration::from_nanos(noisy_ns));
+    }
+    let trends = report.round_trends();
+    assert_eq!(trends.len(), 2);
+    assert_eq!(trends[0].algorithm, tuple);
+    assert_eq!(trends[0].changes, [-50.0, 0.0, -50.0]);
+    assert!(trends[0].monotone);
+    assert_eq!(trends[1].changes, [-75.0, 200.0, -75.0]);
+    assert!(!trends[1].monotone);
+    assert!(BenchmarkReport::default().round_trends().is_empty());
+}
+
+// Low to high, for sparklines
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Latex,
+    Json,
+}
+
+pub struct BenchmarkRow {
+    pub algorithm: String,
+    pub fib_num: u128,
+    pub elapsed: Duration,
+}
+
+pub struct RoundTrend {
+    pub algorithm: String,
+    pub changes: Vec<f64>,
+    pub monotone: bool,
+}
+
+// Every timing from every round of the race, so it can all be rendered
+// at once when a format needs the whole picture (like a LaTeX table)
+#[derive(Default)]
+pub struct BenchmarkReport {
+    pub rounds: Vec<Vec<BenchmarkRow>>,
+}
+
+impl BenchmarkReport {
+    pub fn start_round(&mut self) {
+        self.rounds.push(Vec::new());
+    }
+
+    pub fn record(&mut self, algorithm: &str, fib_num: u128, elapsed: Duration) {
+        if self.rounds.is_empty() {
+            self.start_round();
+        }
+        self.rounds.last_mut().unwrap().push(BenchmarkRow {
+            algorithm: algorithm.to_string(),
+            fib_num,
+            elapsed,
+        });
+    }
+
+    // Just the tabular part, with an \hline between each round
+    pub fn to_latex_tabular(&self) -> String {
+        let mut latex = String::from("\\begin{tabular}{|l|r|r|}\n\\hline\n");
+        latex.push_str("Algorithm & $n$ & Time (ns) \\\\\n");
+        for round in &self.rounds {
+            latex.push_str("\\hline\n");
+            for row in round {
+                latex.push_str(&format!(
+                    "{} & {} & {} \\\\\n",
+                    escape_latex(&row.algorithm),
+                    row.fib_num,
+                    row.elapsed.as_nanos()
+                ));
+            }
+        }
+        latex.push_str("\\hline\n\\end{tabular}");
+        latex
+    }
+
+    // The tabular wrapped in a table float, since that is where
+    // \caption and \label have to live
+    pub fn to_latex(&self) -> String {
+        format!(
+            "\\begin{{table}}[ht]\n\\centering\n{}\n\\caption{{Fibonacci race timings over {} rounds}}\n\\label{{fig:fib-race}}\n\\end{{table}}",
+            self.to_latex_tabular(),
+            self.rounds.len()
+        )
+    }
+
+    // A bar for each algorithm in the latest round, as a multiple of the
+    // fastest one. The slowest bar gets all the room that is left in
+    // width after the labels
+    pub fn to_scale_plot(&self, width: usize) -> Vec<String> {
+        let round = match self.rounds.last() {
+            Some(round) if !round.is_empty() => round,
+            _ => return Vec::new(),
+        };
+        let fastest = round.iter().map(|row| row.elapsed).min().unwrap();
+        let fastest = fastest.as_nanos().max(1) as f64;
+        let scales: Vec<f64> = round
+            .iter()
+            .map(|row| row.elapsed.as_nanos() as f64 / fastest)
+            .collect();
+        let labels: Vec<String> = scales
+            .iter()
+            .map(|scale| format!("{:.1}×", scale))
+            .collect();
+
+        let name_width = round.iter().map(|row| row.algorithm.len()).max().unwrap();
+        let label_width = labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap();
+        let bar_width = width.saturating_sub(name_width + label_width + 2).max(1);
+        let slowest = scales.iter().cloned().fold(1.0, f64::max);
+
+        round
+            .iter()
+            .zip(scales.iter().zip(&labels))
+            .map(|(row, (scale, label))| {
+                let bar = ((scale / slowest) * bar_width as f64).round().max(1.0) as usize;
+                format!(
+                    "{:name_width$} {} {}",
+                    row.algorithm,
+                    "█".repeat(bar),
+                    label,
+                    name_width = name_width
+                )
+            })
+            .collect()
+    }
+
+    // Each algorithm (in the order the first round ran them) with its
+    // time in nanoseconds from every round
+    fn timings_by_algorithm(&self) -> Vec<(&str, Vec<u128>)> {
+        let first = match self.rounds.first() {
+            Some(round) => round,
+            None => return Vec::new(),
+        };
+        first
+            .iter()
+            .map(|row| {
+                let timings = self
+                    .rounds
+                    .iter()
+                    .flat_map(|round| round.iter().find(|other| other.algorithm == row.algorithm))
+                    .map(|row| row.elapsed.as_nanos())
+                    .collect();
+                (row.algorithm.as_str(), timings)
+            })
+            .collect()
+    }
+
+    // A spark per round for each algorithm, scaled between its own
+    // fastest and slowest time so the trend shows even when one
+    // algorithm is way slower than the rest
+    pub fn to_sparklines(&self) -> Vec<String> {
+        let timings = self.timings_by_algorithm();
+        let name_width = timings
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        timings
+            .iter()
+            .map(|(name, timings)| {
+                let min = *timings.iter().min().unwrap();
+                let max = *timings.iter().max().unwrap();
+                let sparks: String = timings
+                    .iter()
+                    .map(|&ns| {
+                        let level = (ns - min) as f64 / (max - min).max(1) as f64;
+                        SPARKS[(level * 7.0).round() as usize]
+                    })
+                    .collect();
+                format!(
+                    "{:name_width$} {} {} to {}",
+                    name,
+                    sparks,
+                    humanize_duration(min),
+                    humanize_duration(max),
+                    name_width = name_width
+                )
+            })
+            .collect()
+    }
+
+    // How each algorithm's time changed from one round to the next (as a
+    // percentage, negative being faster), and whether it only ever got
+    // faster or stayed the same like warm caches should make it
+    pub fn round_trends(&self) -> Vec<RoundTrend> {
+        self.timings_by_algorithm()
+            .into_iter()
+            .map(|(name, timings)| RoundTrend {
+                algorithm: name.to_string(),
+                changes: timings
+                    .windows(2)
+                    .map(|pair| 100.0 * (pair[1] as f64 - pair[0] as f64) / pair[0].max(1) as f64)
+                    .collect(),
+                monotone: timings.windows(2).all(|pair| pair[1] <= pair[0]),
+            })
+            .collect()
+    }
+
+    // One flat array of rows, each one knowing which round it was from
+    pub fn to_json(&self) -> String {
+        let rows: Vec<String> = self
+            .rounds
+            .iter()
+            .enumerate()
+            .flat_map(|(round, rows)| {
+                rows.iter().map(move |row| {
+                    format!(
+                        "  {{\"round\": {}, \"algorithm\": \"{}\", \"n\": {}, \"elapsed_ns\": {}}}",
+                        round + 1,
+                        escape_json(&row.algorithm),
+                        row.fib_num,
+                        row.elapsed.as_nanos()
+                    )
+                })
+            })
+            .collect();
+        if rows.is_empty() {
+            return "[]".to_string();
+        }
+        format!("[\n{}\n]", rows.join(",\n"))
+    }
+}
+
+pub fn escape_json(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn escape_latex(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Nanoseconds in whichever unit keeps the number small enough to read.
+// The unit switches where two decimal places would round up to 1000
+// (999.995 µs on is 1.00 ms), not at exactly 1000
+pub fn humanize_duration(ns: u128) -> String {
+    match ns {
+        0..=999 => format!("{} ns", ns),
+        1_000..=999_994 => format!("{:.2} µs", ns as f64 / 1e3),
+        999_995..=999_994_999 => format!("{:.2} ms", ns as f64 / 1e6),
+        _ => format!("{:.2} s", ns as f64 / 1e9),
+    }
+}
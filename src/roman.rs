@@ -0,0 +1,68 @@
+use crate::better_dynamic_fib;
+use std::convert::TryFrom;
+
+#[test]
+fn test_to_roman() {
+    assert_eq!(to_roman(0), None);
+    assert_eq!(to_roman(4).as_deref(), Some("IV"));
+    assert_eq!(to_roman(9).as_deref(), Some("IX"));
+    assert_eq!(to_roman(1994).as_deref(), Some("MCMXCIV"));
+    assert_eq!(to_roman(3999).as_deref(), Some("MMMCMXCIX"));
+    assert_eq!(to_roman(4000), None);
+}
+
+#[test]
+fn test_fib_roman() {
+    assert_eq!(fib_roman(0), None);
+    assert_eq!(fib_roman(1).as_deref(), Some("I"));
+    assert_eq!(fib_roman(7).as_deref(), Some("XIII"));
+    assert_eq!(fib_roman(10).as_deref(), Some("LV"));
+    // F(18) = 2584 is the last one small enough
+    assert_eq!(fib_roman(18).as_deref(), Some("MMDLXXXIV"));
+    assert_eq!(fib_roman(19), None);
+    assert_eq!(fib_roman(1000), None);
+}
+
+const NUMERALS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+// The most Roman numerals can do (without bars over letters for
+// thousands) is MMMCMXCIX, and there's no numeral for 0 at all
+pub const MAX_ROMAN: u32 = 3999;
+
+// Standard subtractive notation, so 4 is IV and not IIII
+pub fn to_roman(mut n: u32) -> Option<String> {
+    if n == 0 || n > MAX_ROMAN {
+        return None;
+    }
+    let mut roman = String::new();
+    for &(value, numeral) in NUMERALS.iter() {
+        while n >= value {
+            roman.push_str(numeral);
+            n -= value;
+        }
+    }
+    Some(roman)
+}
+
+// F(k) in Roman numerals, which only works up through F(18)
+pub fn fib_roman(k: u32) -> Option<String> {
+    // anything past this would be way past MAX_ROMAN anyway (and overflow)
+    if k > 30 {
+        return None;
+    }
+    to_roman(u32::try_from(better_dynamic_fib(k as u128)).ok()?)
+}
@@ -0,0 +1,122 @@
+use crate::iter::fibonacci_pair_iter;
+use crate::FIB_TABLE;
+
+#[test]
+fn test_scan_for_fibonacci_subsequence() {
+    let data = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34];
+    assert_eq!(
+        scan_for_fibonacci_subsequence(&data),
+        [(0, 10, data.iter().map(|&byte| byte as u128).collect())]
+    );
+
+    let data = [7, 2, 3, 5, 8, 5, 8, 13, 9, 1, 2, 200, 89, 144, 233, 121];
+    assert_eq!(
+        scan_for_fibonacci_subsequence(&data),
+        [
+            (1, 5, vec![2, 3, 5, 8]),
+            (5, 8, vec![5, 8, 13]),
+            (12, 15, vec![89, 144, 233]),
+        ]
+    );
+    // the middle 1 is the end of one run and the start of the next
+    assert_eq!(
+        scan_for_fibonacci_subsequence(&[0, 1, 1, 1, 2]),
+        [(0, 3, vec![0, 1, 1]), (2, 5, vec![1, 1, 2])]
+    );
+    assert!(scan_for_fibonacci_subsequence(&[1, 2, 4, 8, 16]).is_empty());
+    assert!(scan_for_fibonacci_subsequence(&[]).is_empty());
+}
+
+#[test]
+fn test_is_fibonacci_self_similar() {
+    assert!(is_fibonacci_self_similar(&[5, 8, 13, 21]));
+    assert!(is_fibonacci_self_similar(&[0, 1, 1, 2]));
+    assert!(is_fibonacci_self_similar(&[1, 1]));
+    assert!(is_fibonacci_self_similar(&[144]));
+    assert!(is_fibonacci_self_similar(&[]));
+    assert!(is_fibonacci_self_similar(&FIB_TABLE));
+    // each is right, but there's a gap
+    assert!(!is_fibonacci_self_similar(&[5, 13, 21]));
+    assert!(!is_fibonacci_self_similar(&[8, 5, 3]));
+    // follows the rule, but not from the start
+    assert!(!is_fibonacci_self_similar(&[2, 1, 3, 4, 7]));
+    assert!(!is_fibonacci_self_similar(&[4]));
+}
+
+#[test]
+fn test_longest_fibonacci_subsequence() {
+    assert_eq!(
+        longest_fibonacci_subsequence(&[4, 3, 5, 8, 9, 21, 34, 55, 89, 7]),
+        (8, 4)
+    );
+    // the 1 is F(1) or F(2), and only F(1) runs on into 1
+    assert_eq!(longest_fibonacci_subsequence(&[7, 1, 1, 2, 3]), (1, 4));
+    assert_eq!(longest_fibonacci_subsequence(&[7, 1, 2]), (2, 2));
+    // ties go to the first one
+    assert_eq!(longest_fibonacci_subsequence(&[13, 21, 4, 2, 3]), (7, 2));
+    assert_eq!(longest_fibonacci_subsequence(&[4, 6]), (0, 0));
+    assert_eq!(longest_fibonacci_subsequence(&FIB_TABLE), (0, 187));
+}
+
+// Whether (a, b) is (F(k), F(k + 1)) for some k
+fn consecutive_fibs(a: u128, b: u128) -> bool {
+    fibonacci_pair_iter()
+        .take_while(|&(fib, _)| fib <= a)
+        .any(|pair| pair == (a, b))
+}
+
+// Every run of 3 or more bytes in a row that are Fibonacci numbers in a
+// row too, as (start, end, values) with data[start..end] being the run.
+// Runs go as far as they can, and the next one can only start where the
+// last one ended, since two Fibonacci numbers in a row decide the rest
+// (so runs share at most one byte). One byte can't hold anything past
+// F(13) = 233, so runs are never more than 14 long (0 through 233)
+pub fn scan_for_fibonacci_subsequence(data: &[u8]) -> Vec<(usize, usize, Vec<u128>)> {
+    let values: Vec<u128> = data.iter().map(|&byte| byte as u128).collect();
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start + 2 < values.len() {
+        if !consecutive_fibs(values[start], values[start + 1]) {
+            start += 1;
+            continue;
+        }
+        let mut end = start + 2;
+        while end < values.len() && values[end] == values[end - 1] + values[end - 2] {
+            end += 1;
+        }
+        if end - start >= 3 {
+            runs.push((start, end, values[start..end].to_vec()));
+            start = end - 1;
+        } else {
+            start += 1;
+        }
+    }
+    runs
+}
+
+// Whether seq is F(k), F(k + 1), F(k + 2), ... for some k. Nothing at
+// all counts, the same as an empty slice being a part of any other
+pub fn is_fibonacci_self_similar(seq: &[u128]) -> bool {
+    seq.is_empty() || FIB_TABLE.windows(seq.len()).any(|window| window == seq)
+}
+
+// (k, length) for the longest stretch of data that's F(k), F(k + 1),
+// ..., the first one found if there's a tie, or (0, 0) if nothing in
+// data is a Fibonacci number at all
+pub fn longest_fibonacci_subsequence(data: &[u128]) -> (usize, usize) {
+    let mut longest = (0, 0);
+    for start in 0..data.len() {
+        // 1 is both F(1) and F(2), so try every k it could be
+        for k in (0..FIB_TABLE.len()).filter(|&k| FIB_TABLE[k] == data[start]) {
+            let length = data[start..]
+                .iter()
+                .zip(&FIB_TABLE[k..])
+                .take_while(|(value, fib)| value == fib)
+                .count();
+            if length > longest.1 {
+                longest = (k, length);
+            }
+        }
+    }
+    longest
+}
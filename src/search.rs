@@ -0,0 +1,56 @@
+use std::cmp::Ordering;
+
+#[test]
+fn test_fibonacci_search() {
+    assert_eq!(fibonacci_search(&[], &1), None);
+    for size in 1..=40 {
+        let haystack: Vec<u32> = (0..size).map(|i| i * 2).collect();
+        for (i, needle) in haystack.iter().enumerate() {
+            assert_eq!(fibonacci_search(&haystack, needle), Some(i));
+            assert_eq!(fibonacci_search(&haystack, &(needle + 1)), None);
+        }
+        assert_eq!(fibonacci_search(&haystack, &(size * 2)), None);
+    }
+}
+
+// Like a binary search, but instead of cutting the range in half it
+// gets split into two Fibonacci sized pieces, F(k - 2) and F(k - 1).
+// Going right or left just means stepping down one or two Fibonacci
+// numbers, so it only ever needs addition and subtraction (no dividing
+// by 2), which used to matter a lot more than it does today
+pub fn fibonacci_search<T: Ord>(haystack: &[T], needle: &T) -> Option<usize> {
+    // the smallest Fibonacci number at least as big as the haystack,
+    // along with the two before it
+    let (mut fib2, mut fib1, mut fib) = (0, 1, 1);
+    while fib < haystack.len() {
+        fib2 = fib1;
+        fib1 = fib;
+        fib = fib1 + fib2;
+    }
+
+    // everything before low has been ruled out
+    let mut low = 0;
+    while fib > 1 {
+        let i = (low + fib2 - 1).min(haystack.len() - 1);
+        match haystack[i].cmp(needle) {
+            Ordering::Less => {
+                fib = fib1;
+                fib1 = fib2;
+                fib2 = fib - fib1;
+                low = i + 1;
+            }
+            Ordering::Greater => {
+                fib = fib2;
+                fib1 -= fib2;
+                fib2 = fib - fib1;
+            }
+            Ordering::Equal => return Some(i),
+        }
+    }
+
+    if fib1 == 1 && low < haystack.len() && haystack[low] == *needle {
+        Some(low)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,44 @@
+use crate::iter::fibonacci_iter;
+use std::convert::TryFrom;
+
+#[test]
+fn test_fibonacci_divisibility_sieve() {
+    let sieve = fibonacci_divisibility_sieve(100);
+    assert_eq!(sieve.len(), 101);
+    assert!(!sieve[0] && !sieve[1]);
+    for k in (5..=100).step_by(5).chain((8..=100).step_by(8)) {
+        assert!(sieve[k], "{} should be marked", k);
+    }
+    // 7 and 11 and 17 have nothing but themselves and 1
+    let unmarked: Vec<usize> = (0..=100).filter(|&k| !sieve[k]).collect();
+    assert_eq!(
+        unmarked,
+        [
+            0, 1, 7, 11, 17, 19, 23, 29, 31, 37, 41, 43, 47, 49, 53, 59, 61, 67, 71, 73, 77, 79,
+            83, 97
+        ]
+    );
+    assert_eq!(fibonacci_divisibility_sieve(0), [false]);
+}
+
+// The Sieve of Eratosthenes, but crossing off the multiples of the
+// Fibonacci numbers instead of the primes: sieve[k] is true when some
+// Fibonacci number bigger than 1 divides k (so every even k, thanks to
+// F(3) = 2). Starting at each one means the Fibonacci numbers count as
+// their own factor, and 0 is left alone since it would be "divisible"
+// by all of them
+pub fn fibonacci_divisibility_sieve(limit: u128) -> Vec<bool> {
+    let limit = usize::try_from(limit).expect("that sieve would never fit in memory");
+    let mut sieve = vec![false; limit + 1];
+    // F(3) = 2 on, since F(1) = F(2) = 1 divides everything
+    for fib in fibonacci_iter().skip(3) {
+        let fib = fib as usize;
+        if fib > limit {
+            break;
+        }
+        for multiple in (fib..=limit).step_by(fib) {
+            sieve[multiple] = true;
+        }
+    }
+    sieve
+}
@@ -0,0 +1,62 @@
+use crate::better_dynamic_fib;
+use std::simd::cmp::SimdPartialOrd;
+use std::simd::{u64x4, Select};
+
+#[test]
+fn test_simd_batch_fib() {
+    let inputs: Vec<u128> = (0..=MAX_SIMD_FIB_INDEX).rev().collect();
+    let scalar: Vec<u128> = inputs.iter().map(|&n| better_dynamic_fib(n)).collect();
+    assert_eq!(simd_batch_fib(&inputs), scalar);
+
+    // not a multiple of 4, and mixed in with ones too big for a lane
+    let inputs = [20, 186, 0, 93, 94, 1, 7];
+    let scalar: Vec<u128> = inputs.iter().map(|&n| better_dynamic_fib(n)).collect();
+    assert_eq!(simd_batch_fib(&inputs), scalar);
+    assert!(simd_batch_fib(&[]).is_empty());
+}
+
+// F(93) is the last one that fits in a u64 lane
+pub const MAX_SIMD_FIB_INDEX: u128 = 93;
+
+// The better_dynamic_fib tuple, but four of them at a time, one per
+// lane. Every lane takes a step each time around and the ones that have
+// already reached their n just keep what they had (the sums they throw
+// away are allowed to wrap)
+fn simd_fib(fib_nums: u64x4) -> u64x4 {
+    let mut memo = (u64x4::splat(0), u64x4::splat(1));
+    let steps = fib_nums.to_array().iter().copied().max().unwrap();
+    for step in 0..steps {
+        let active = u64x4::splat(step).simd_lt(fib_nums);
+        let next = memo.0 + memo.1;
+        memo = (active.select(memo.1, memo.0), active.select(next, memo.1));
+    }
+    memo.0
+}
+
+// F(n) for every n in inputs, in the same order. Anything past
+// MAX_SIMD_FIB_INDEX doesn't fit in a lane, so those are done one at a
+// time the usual way
+pub fn simd_batch_fib(inputs: &[u128]) -> Vec<u128> {
+    let mut results = vec![0; inputs.len()];
+    let mut small = Vec::new();
+    for (i, &fib_num) in inputs.iter().enumerate() {
+        if fib_num <= MAX_SIMD_FIB_INDEX {
+            small.push(i);
+        } else {
+            results[i] = better_dynamic_fib(fib_num);
+        }
+    }
+
+    for chunk in small.chunks(4) {
+        // unused lanes just work out F(0)
+        let mut fib_nums = [0; 4];
+        for (lane, &i) in chunk.iter().enumerate() {
+            fib_nums[lane] = inputs[i] as u64;
+        }
+        let fibs = simd_fib(u64x4::from_array(fib_nums)).to_array();
+        for (lane, &i) in chunk.iter().enumerate() {
+            results[i] = fibs[lane] as u128;
+        }
+    }
+    results
+}
@@ -0,0 +1,177 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::iter;
+
+#[test]
+fn test_fib_skip_list() {
+    let mut list = FibSkipList::new();
+    assert!(list.is_empty());
+    for value in &[21, 3, 144, 1, 8, 55, 2, 13, 89, 5, 34] {
+        assert!(list.insert(*value));
+    }
+    assert!(!list.insert(8));
+    assert_eq!(list.len(), 11);
+    assert!(list.contains(&55));
+    assert!(!list.contains(&4));
+
+    assert!(list.remove(&55));
+    assert!(!list.remove(&55));
+    assert!(list.remove(&1));
+    assert!(list.remove(&144));
+    assert!(list.insert(0));
+    let values: Vec<i32> = list.iter().copied().collect();
+    assert_eq!(values, [0, 2, 3, 5, 8, 13, 21, 34, 89]);
+
+    let mut list = FibSkipList::new();
+    for value in (0..1000).rev() {
+        list.insert(value * 7 % 1000);
+    }
+    assert!(list.iter().copied().eq(0..1000));
+}
+
+// 1/φ, the chance a node that made it to one level also gets promoted
+// to the next one up
+const PROMOTION: f64 = 0.618_033_988_749_895;
+// Way more levels than anything reasonable needs (expected height is
+// log_φ(n), so about 48 levels would mean billions of values)
+const MAX_LEVEL: usize = 48;
+// The head node starts every level and never holds a value
+const HEAD: usize = 0;
+
+struct Node<T> {
+    value: Option<T>,
+    next: Vec<Option<usize>>,
+}
+
+// A sorted set made of linked lists stacked on top of each other, each
+// one skipping over more of the list below it. Promoting with 1/φ odds
+// instead of the usual 1/2 makes a taller, skinnier stack, where the
+// chance of being on a given level shrinks by φ each level up. The nodes
+// live in a Vec and link to each other by index
+pub struct FibSkipList<T: Ord> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+    len: usize,
+    rng: StdRng,
+}
+
+impl<T: Ord> Default for FibSkipList<T> {
+    fn default() -> Self {
+        FibSkipList::new()
+    }
+}
+
+impl<T: Ord> FibSkipList<T> {
+    pub fn new() -> FibSkipList<T> {
+        FibSkipList {
+            nodes: vec![Node {
+                value: None,
+                next: vec![None; MAX_LEVEL],
+            }],
+            free: Vec::new(),
+            len: 0,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn value(&self, index: usize) -> &T {
+        self.nodes[index].value.as_ref().unwrap()
+    }
+
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+        while level < MAX_LEVEL && self.rng.gen::<f64>() < PROMOTION {
+            level += 1;
+        }
+        level
+    }
+
+    // The last node before where value goes, on every level. Start at
+    // the top and run right as far as we can before dropping down
+    fn predecessors(&self, value: &T) -> [usize; MAX_LEVEL] {
+        let mut predecessors = [HEAD; MAX_LEVEL];
+        let mut current = HEAD;
+        for level in (0..MAX_LEVEL).rev() {
+            while let Some(next) = self.nodes[current].next[level] {
+                if self.value(next) >= value {
+                    break;
+                }
+                current = next;
+            }
+            predecessors[level] = current;
+        }
+        predecessors
+    }
+
+    fn find(&self, value: &T, predecessors: &[usize; MAX_LEVEL]) -> Option<usize> {
+        self.nodes[predecessors[0]].next[0].filter(|&next| self.value(next) == value)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value, &self.predecessors(value)).is_some()
+    }
+
+    // Like a set, returns false (and changes nothing) if it was already there
+    pub fn insert(&mut self, value: T) -> bool {
+        let predecessors = self.predecessors(&value);
+        if self.find(&value, &predecessors).is_some() {
+            return false;
+        }
+
+        let level = self.random_level();
+        let node = Node {
+            value: Some(value),
+            next: (0..level)
+                .map(|level| self.nodes[predecessors[level]].next[level])
+                .collect(),
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        for (level, &predecessor) in predecessors.iter().enumerate().take(level) {
+            self.nodes[predecessor].next[level] = Some(index);
+        }
+        self.len += 1;
+        true
+    }
+
+    pub fn remove(&mut self, value: &T) -> bool {
+        let predecessors = self.predecessors(value);
+        let index = match self.find(value, &predecessors) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let next = std::mem::take(&mut self.nodes[index].next);
+        for (level, &predecessor) in predecessors.iter().enumerate().take(next.len()) {
+            self.nodes[predecessor].next[level] = next[level];
+        }
+        self.nodes[index].value = None;
+        self.free.push(index);
+        self.len -= 1;
+        true
+    }
+
+    // In order, by just walking the bottom level
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        iter::successors(self.nodes[HEAD].next[0], move |&index| {
+            self.nodes[index].next[0]
+        })
+        .map(move |index| self.value(index))
+    }
+}
@@ -0,0 +1,129 @@
+#[test]
+fn test_fibonacci_spiral_path() {
+    assert_eq!(
+        fibonacci_spiral_path(2, 4),
+        [
+            (0, 0),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (0, 2),
+            (0, 3),
+            (1, 3),
+            (1, 2)
+        ]
+    );
+    assert!(fibonacci_spiral_path(0, 5).is_empty());
+
+    for size in crate::iter::fibonacci_iter()
+        .skip(1)
+        .take_while(|&size| size <= 1000)
+    {
+        let size = size as usize;
+        for rows in (1..=size).filter(|rows| size.is_multiple_of(*rows)) {
+            let cols = size / rows;
+            let path = fibonacci_spiral_path(rows, cols);
+            let mut seen = vec![false; size];
+            for &(row, col) in &path {
+                assert!(row < rows && col < cols);
+                assert!(
+                    !seen[row * cols + col],
+                    "{}x{} goes back to {:?}",
+                    rows,
+                    cols,
+                    (row, col)
+                );
+                seen[row * cols + col] = true;
+            }
+            assert_eq!(path.len(), size, "{}x{}", rows, cols);
+        }
+    }
+}
+
+#[test]
+fn test_render_grid_path() {
+    let path = fibonacci_spiral_path(2, 4);
+    assert_eq!(render_grid_path(2, 4, &path), "1 2 5 6\n4 3 8 7");
+    assert_eq!(render_grid_path(2, 3, &[(0, 0), (1, 2)]), "1 . .\n. . 2");
+}
+
+// Right, down, left, up
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+// Every cell of the grid, in the order a walk from the top left corner
+// gets to them: F(1) steps right, F(2) down, F(3) left, F(4) up, F(5)
+// right again and so on (a spiral that winds in, since the walk turns
+// early at the edge or when the next cell has already been visited). If
+// all four directions are blocked the walk jumps to the first cell it
+// hasn't visited yet and carries on from there, so every cell still
+// gets visited exactly once, whatever the grid's size
+pub fn fibonacci_spiral_path(grid_rows: usize, grid_cols: usize) -> Vec<(usize, usize)> {
+    let cells = grid_rows * grid_cols;
+    let mut path = Vec::with_capacity(cells);
+    if cells == 0 {
+        return path;
+    }
+    let mut visited = vec![false; cells];
+    let mut position = (0, 0);
+    visited[0] = true;
+    path.push(position);
+
+    // (F(n), F(n + 1)), with no need to go past the number of cells
+    let mut lengths: (usize, usize) = (1, 1);
+    let mut direction = 0;
+    let mut blocked = 0;
+    while path.len() < cells {
+        let (row_step, col_step) = DIRECTIONS[direction];
+        let mut steps = 0;
+        while steps < lengths.0 {
+            let next = (
+                position.0 as isize + row_step,
+                position.1 as isize + col_step,
+            );
+            let in_grid = (0..grid_rows as isize).contains(&next.0)
+                && (0..grid_cols as isize).contains(&next.1);
+            if !in_grid || visited[next.0 as usize * grid_cols + next.1 as usize] {
+                break;
+            }
+            position = (next.0 as usize, next.1 as usize);
+            visited[position.0 * grid_cols + position.1] = true;
+            path.push(position);
+            steps += 1;
+        }
+
+        blocked = if steps == 0 { blocked + 1 } else { 0 };
+        if blocked == DIRECTIONS.len() {
+            let cell = visited.iter().position(|&visited| !visited).unwrap();
+            position = (cell / grid_cols, cell % grid_cols);
+            visited[cell] = true;
+            path.push(position);
+            blocked = 0;
+        }
+        direction = (direction + 1) % DIRECTIONS.len();
+        lengths = (lengths.1, (lengths.0 + lengths.1).min(cells));
+    }
+    path
+}
+
+// The grid with the step (counting from 1) each cell was visited on, or
+// a . for cells the path never gets to
+pub fn render_grid_path(rows: usize, cols: usize, path: &[(usize, usize)]) -> String {
+    let mut order = vec![None; rows * cols];
+    for (step, &(row, col)) in path.iter().enumerate() {
+        order[row * cols + col] = Some(step + 1);
+    }
+    let width = path.len().to_string().len();
+    order
+        .chunks(cols.max(1))
+        .map(|row| {
+            row.iter()
+                .map(|step| match step {
+                    Some(step) => format!("{:>width$}", step, width = width),
+                    None => format!("{:>width$}", ".", width = width),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,104 @@
+use crate::iter::fibonacci_iter;
+
+#[test]
+fn test_fib_stats() {
+    let stats = fib_stats(10).unwrap();
+    assert_eq!(stats.mean, 8.8);
+    assert_eq!((stats.min, stats.max), (0.0, 34.0));
+    assert_eq!(stats.median, 4.0);
+    assert!((stats.std_dev - 10.467091286503619).abs() < 1e-9);
+    // 8 is closest to 8.8
+    assert_eq!(stats.closest_to_mean, 6);
+    assert!(!stats.approximate);
+
+    assert!(!fib_stats(187).unwrap().approximate);
+    let stats = fib_stats(1000).unwrap();
+    assert!(stats.approximate);
+    assert!(stats.max.is_finite());
+    for &n in &[740, MAX_STATS_N - 1, MAX_STATS_N] {
+        let stats = fib_stats(n).unwrap();
+        for value in &[stats.max, stats.mean, stats.median, stats.std_dev] {
+            assert!(value.is_finite(), "fib_stats({}) has {}", n, value);
+        }
+        // the mean is F(n + 1) / n give or take, way past the middle
+        assert!(stats.closest_to_mean > n / 2);
+    }
+    assert!(fib_stats(0).is_none());
+}
+
+// F(1476) is the last one that fits in an f64, even approximately
+pub const MAX_STATS_N: usize = 1477;
+
+pub struct FibStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    // the n whose F(n) is closest to the mean
+    pub closest_to_mean: usize,
+    // past F(186) the values are only f64 approximations
+    pub approximate: bool,
+}
+
+// Summary statistics of F(0) through F(n - 1). The values are exact
+// while they fit in a u128 (though the math is all done in f64 either
+// way, because even their sum doesn't fit), after that they're built up
+// in f64 and are only approximate. Up there the sum, and the squares
+// for the standard deviation, would be too big even for an f64, so it's
+// all worked out relative to about the biggest value and scaled back up
+// at the end. None for no values at all, or past MAX_STATS_N where F(n)
+// itself doesn't fit
+pub fn fib_stats(n: usize) -> Option<FibStats> {
+    if n == 0 || n > MAX_STATS_N {
+        return None;
+    }
+    let mut values: Vec<f64> = fibonacci_iter().take(n).map(|fib| fib as f64).collect();
+    let approximate = values.len() < n;
+    while values.len() < n {
+        let next = values[values.len() - 1] + values[values.len() - 2];
+        values.push(next);
+    }
+
+    let count = values.len() as f64;
+    // a power of 2 near the biggest value, since dividing by one of
+    // those is exact and the small n come out the same as they would
+    // without any scaling (F(0) on its own is all zeros, nothing to scale)
+    let scale = if values[n - 1] > 0.0 {
+        2f64.powi(values[n - 1].log2().floor() as i32)
+    } else {
+        1.0
+    };
+    let scaled_mean = values.iter().map(|value| value / scale).sum::<f64>() / count;
+    let scaled_variance = values
+        .iter()
+        .map(|value| (value / scale - scaled_mean).powi(2))
+        .sum::<f64>()
+        / count;
+    let mean = scaled_mean * scale;
+    // already sorted, the sequence only goes up. Halved before adding,
+    // since the two biggest add up to more than an f64 holds
+    let median = if n % 2 == 1 {
+        values[n / 2]
+    } else {
+        values[n / 2 - 1] / 2.0 + values[n / 2] / 2.0
+    };
+    let closest_to_mean = (0..n)
+        .min_by(|&a, &b| {
+            (values[a] - mean)
+                .abs()
+                .partial_cmp(&(values[b] - mean).abs())
+                .unwrap()
+        })
+        .unwrap();
+
+    Some(FibStats {
+        min: values[0],
+        max: values[n - 1],
+        mean,
+        median,
+        std_dev: scaled_variance.sqrt() * scale,
+        closest_to_mean,
+        approximate,
+    })
+}
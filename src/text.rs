@@ -0,0 +1,48 @@
+use crate::is_fibonacci;
+use std::collections::HashMap;
+
+#[test]
+fn test_count_fibonacci_in_text() {
+    let text = "1 1 2 4 8 hello 13 -5 21.0 13\n144 1000 0";
+    let counts = count_fibonacci_in_text(text);
+    let mut counts: Vec<(u128, usize)> = counts.into_iter().collect();
+    counts.sort_unstable();
+    assert_eq!(counts, [(0, 1), (1, 2), (2, 1), (8, 1), (13, 2), (144, 1)]);
+    assert!(count_fibonacci_in_text("no numbers here").is_empty());
+}
+
+#[test]
+fn test_text_fibonacci_density() {
+    // 8 of the 10 numbers (hello, -5 and 21.0 don't count at all)
+    let text = "1 1 2 4 8 hello 13 -5 21.0 13\n144 1000 0";
+    assert_eq!(text_fibonacci_density(text), 0.8);
+    assert_eq!(text_fibonacci_density("3 5 8"), 1.0);
+    assert_eq!(text_fibonacci_density("nothing to see"), 0.0);
+}
+
+// Every whitespace separated token that reads as a u128 and is a
+// Fibonacci number, counted up by value
+pub fn count_fibonacci_in_text(text: &str) -> HashMap<u128, usize> {
+    let mut counts = HashMap::new();
+    for value in numbers_in(text).filter(|&value| is_fibonacci(value)) {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Out of the tokens that are numbers at all, the fraction that are
+// Fibonacci numbers (0 if there aren't any numbers)
+pub fn text_fibonacci_density(text: &str) -> f64 {
+    let (numbers, fibs) = numbers_in(text).fold((0, 0), |(numbers, fibs), value| {
+        (numbers + 1, fibs + is_fibonacci(value) as usize)
+    });
+    if numbers == 0 {
+        return 0.0;
+    }
+    fibs as f64 / numbers as f64
+}
+
+fn numbers_in(text: &str) -> impl Iterator<Item = u128> + '_ {
+    text.split_whitespace()
+        .filter_map(|token| token.parse::<u128>().ok())
+}
@@ -0,0 +1,72 @@
+use crate::iter::fibonacci_iter;
+use std::collections::HashMap;
+
+#[test]
+fn test_prefix_search() {
+    let trie = FibTrie::build(20);
+    assert_eq!(trie.prefix_search("1"), [1, 1, 13, 144, 1597]);
+    assert_eq!(trie.prefix_search("2"), [2, 21, 233, 2584]);
+    assert_eq!(trie.prefix_search("67"), [6765]);
+    assert_eq!(trie.prefix_search("6766"), []);
+    assert_eq!(trie.prefix_search("").len(), 21);
+
+    let trie = FibTrie::build(crate::MAX_FIB_INDEX_U128);
+    assert_eq!(
+        trie.prefix_search("3328"),
+        [crate::better_dynamic_fib(crate::MAX_FIB_INDEX_U128)]
+    );
+}
+
+#[derive(Default)]
+pub struct FibTrieNode {
+    pub children: HashMap<char, FibTrieNode>,
+    // the Fibonacci numbers spelled out by the path down to here (F(1)
+    // and F(2) are both 1, so there can be more than one)
+    pub values: Vec<u128>,
+}
+
+// The decimal digits of F(0) through F(n), one trie level per digit,
+// for finding every Fibonacci number that starts a certain way
+#[derive(Default)]
+pub struct FibTrie {
+    root: FibTrieNode,
+}
+
+impl FibTrie {
+    pub fn build(fib_num: u128) -> FibTrie {
+        let mut trie = FibTrie::default();
+        for value in fibonacci_iter().take(fib_num as usize + 1) {
+            trie.insert(value);
+        }
+        trie
+    }
+
+    pub fn insert(&mut self, value: u128) {
+        let mut node = &mut self.root;
+        for digit in value.to_string().chars() {
+            node = node.children.entry(digit).or_default();
+        }
+        node.values.push(value);
+    }
+
+    // Every Fibonacci number in the trie whose digits start with prefix,
+    // smallest first
+    pub fn prefix_search(&self, prefix: &str) -> Vec<u128> {
+        let mut node = &self.root;
+        for digit in prefix.chars() {
+            match node.children.get(&digit) {
+                Some(child) => node = child,
+                None => return Vec::new(),
+            }
+        }
+
+        let mut found = Vec::new();
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            found.extend(&node.values);
+            stack.extend(node.children.values());
+        }
+        found.sort_unstable();
+        found
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+#[test]
+fn test_fib_units() {
+    assert_eq!(Fib::<Meters>::new(3) + Fib::new(5), Fib::new(8));
+    assert_eq!(Fib::<Seconds>::nth(10).value(), 55);
+    assert_eq!(Fib::<Meters>::new(8).to_string(), "8 m");
+    assert_eq!(Fib::<Seconds>::new(13).to_string(), "13 s");
+    assert_eq!(Fib::<Bytes>::new(21).to_string(), "21 B");
+
+    // adding up consecutive ones keeps walking the sequence, units and all
+    let mut a = Fib::<Bytes>::nth(1);
+    let mut b = Fib::<Bytes>::nth(2);
+    for n in 3..=186 {
+        let next = a + b;
+        assert_eq!(next, Fib::nth(n));
+        a = b;
+        b = next;
+    }
+}
+
+// What a Fib is measured in, only ever used as a type so there's
+// nothing in them
+pub trait Unit {
+    const SYMBOL: &'static str;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Meters;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Seconds;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bytes;
+
+impl Unit for Meters {
+    const SYMBOL: &'static str = "m";
+}
+
+impl Unit for Seconds {
+    const SYMBOL: &'static str = "s";
+}
+
+impl Unit for Bytes {
+    const SYMBOL: &'static str = "B";
+}
+
+/// A Fibonacci number with a unit that only the compiler sees. The unit
+/// doesn't take up any room, but it means meters only add to meters
+///
+/// ```
+/// use fibonacci_race::units::{Fib, Meters};
+///
+/// assert_eq!(Fib::<Meters>::new(3) + Fib::<Meters>::new(5), Fib::<Meters>::new(8));
+/// ```
+///
+/// and adding meters to seconds doesn't build at all:
+///
+/// ```compile_fail
+/// use fibonacci_race::units::{Fib, Meters, Seconds};
+///
+/// let nonsense = Fib::<Meters>::new(3) + Fib::<Seconds>::new(5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fib<U>(pub u128, pub PhantomData<U>);
+
+impl<U: Unit> Fib<U> {
+    pub fn new(value: u128) -> Self {
+        Fib(value, PhantomData)
+    }
+
+    // F(n) in this unit, n being at most 186 like lookup_fib
+    pub fn nth(fib_num: u128) -> Self {
+        Fib::new(crate::lookup_fib(fib_num))
+    }
+
+    pub fn value(self) -> u128 {
+        self.0
+    }
+}
+
+impl<U: Unit> Add for Fib<U> {
+    type Output = Fib<U>;
+
+    fn add(self, other: Fib<U>) -> Fib<U> {
+        Fib::new(self.0 + other.0)
+    }
+}
+
+impl<U: Unit> fmt::Display for Fib<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.0, U::SYMBOL)
+    }
+}
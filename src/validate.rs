@@ -0,0 +1,68 @@
+use std::fmt;
+
+#[test]
+fn test_validate_sequence() {
+    for fib_num in 2..=crate::MAX_FIB_INDEX_U128 {
+        assert_eq!(
+            validate_sequence(crate::better_dynamic_fib, fib_num),
+            Ok(())
+        );
+    }
+
+    // off by one as soon as it's past F(10)
+    let corrupted = |fib_num| crate::better_dynamic_fib(fib_num) + (fib_num > 10) as u128;
+    assert_eq!(validate_sequence(corrupted, 10), Ok(()));
+    let failure = validate_sequence(corrupted, 11).unwrap_err();
+    assert_eq!(
+        failure,
+        SequenceMismatch {
+            fib_num: 11,
+            results: [34, 55, 90],
+        }
+    );
+    assert_eq!(
+        failure.to_string(),
+        "F(11) came out 90, but F(9) + F(10) came out 34 + 55 = 89"
+    );
+}
+
+// What compute came up with for n - 2, n - 1 and n when they didn't add up
+#[derive(Debug, PartialEq, Eq)]
+pub struct SequenceMismatch {
+    pub fib_num: u128,
+    pub results: [u128; 3],
+}
+
+impl fmt::Display for SequenceMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [two_back, one_back, result] = self.results;
+        write!(
+            f,
+            "F({}) came out {}, but F({}) + F({}) came out {} + {} = {}",
+            self.fib_num,
+            result,
+            self.fib_num - 2,
+            self.fib_num - 1,
+            two_back,
+            one_back,
+            two_back.wrapping_add(one_back)
+        )
+    }
+}
+
+// Whatever compute is, its answers for n - 2, n - 1 and n had better
+// follow F(n) = F(n - 1) + F(n - 2). It doesn't prove the answers are
+// right (any sequence built the same way passes) but nearly any bug
+// throws it off
+pub fn validate_sequence(
+    compute: impl Fn(u128) -> u128,
+    fib_num: u128,
+) -> Result<(), SequenceMismatch> {
+    assert!(fib_num >= 2, "F({}) has nothing to add up", fib_num);
+    let results = [compute(fib_num - 2), compute(fib_num - 1), compute(fib_num)];
+    if results[0].checked_add(results[1]) == Some(results[2]) {
+        Ok(())
+    } else {
+        Err(SequenceMismatch { fib_num, results })
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{const_fib, is_fibonacci, lookup_fib, MAX_FIB_INDEX_U128};
+
+#[test]
+fn test_fibbinary() {
+    assert_eq!(to_fibbinary(0), 0);
+    assert_eq!(to_fibbinary(4), 0b101); // 3 + 1
+    assert_eq!(to_fibbinary(12), 0b10101); // 8 + 3 + 1
+    for x in 0..=10000 {
+        assert_eq!(from_fibbinary(to_fibbinary(x)), x);
+        // only fibbinary numbers (no two 1 bits in a row) come back
+        if x & (x >> 1) == 0 {
+            assert_eq!(to_fibbinary(from_fibbinary(x)), x);
+        }
+    }
+    assert_eq!(
+        from_fibbinary(to_fibbinary(u64::MAX as u128)),
+        u64::MAX as u128
+    );
+}
+
+#[test]
+fn test_zeckendorf() {
+    assert_eq!(zeckendorf(0), []);
+    assert_eq!(zeckendorf(1), [2]);
+    assert_eq!(zeckendorf(100), [11, 6, 4]); // 89 + 8 + 3
+    assert_eq!(from_zeckendorf(&[11, 6, 4]), 100);
+    assert_eq!(from_zeckendorf(&zeckendorf(u128::MAX)), u128::MAX);
+    assert!(verify_zeckendorf_uniqueness(10000));
+}
+
+#[test]
+fn test_zeckendorf_add() {
+    for a in 1..=100 {
+        for b in 1..=100 {
+            let sum = zeckendorf_add(&zeckendorf(a), &zeckendorf(b));
+            assert_eq!(from_zeckendorf(&sum), a + b);
+            assert_eq!(sum, zeckendorf(a + b));
+        }
+    }
+    assert_eq!(zeckendorf_add(&[], &[]), []);
+    assert_eq!(zeckendorf_add(&[186], &[185]), [187]);
+}
+
+// Bit k of a fibbinary number stands for F(k + 2), so the bits are
+// worth 1, 2, 3, 5, 8... (skipping F(0) and the duplicate F(1)). The
+// biggest is bit 127 which is F(129)
+const BIT_VALUES: [u128; 128] = bit_values();
+
+const fn bit_values() -> [u128; 128] {
+    let mut values = [0; 128];
+    let mut bit = 0;
+    while bit < 128 {
+        values[bit] = const_fib(bit as u128 + 2);
+        bit += 1;
+    }
+    values
+}
+
+// Zeckendorf's theorem says every number is a sum of non-consecutive
+// Fibonacci numbers in exactly one way, and greedily taking the biggest
+// one that fits finds it. Written out as bits that never has two 1s in
+// a row. Only works below F(130), past that the bits won't fit in a u128
+pub fn to_fibbinary(n: u128) -> u128 {
+    assert!(
+        n < const_fib(130),
+        "{} needs more than 128 fibbinary bits",
+        n
+    );
+
+    let mut fibbinary = 0;
+    let mut remainder = n;
+    for (bit, &value) in BIT_VALUES.iter().enumerate().rev() {
+        if value <= remainder {
+            fibbinary |= 1 << bit;
+            remainder -= value;
+        }
+    }
+    fibbinary
+}
+
+pub fn from_fibbinary(fibbinary: u128) -> u128 {
+    BIT_VALUES
+        .iter()
+        .enumerate()
+        .filter(|&(bit, _)| fibbinary & (1 << bit) != 0)
+        .map(|(_, value)| value)
+        .sum()
+}
+
+// The same greedy split as to_fibbinary, but as the indices of the
+// Fibonacci numbers used (biggest first, never F(0) or F(1)) so it works
+// all the way up to u128::MAX
+pub fn zeckendorf(n: u128) -> Vec<u128> {
+    let mut indices = Vec::new();
+    let mut remainder = n;
+    for fib_num in (2..=MAX_FIB_INDEX_U128).rev() {
+        if lookup_fib(fib_num) <= remainder {
+            indices.push(fib_num);
+            remainder -= lookup_fib(fib_num);
+        }
+    }
+    indices
+}
+
+pub fn from_zeckendorf(indices: &[u128]) -> u128 {
+    indices.iter().map(|&fib_num| lookup_fib(fib_num)).sum()
+}
+
+// Two Zeckendorf representations (biggest index first, like zeckendorf
+// gives) added up without ever turning them back into numbers. Every
+// index from both goes in a pile, and then the piles get carried until
+// none has more than one and no two are next to each other:
+//   F(k) + F(k + 1) = F(k + 2)
+//   2F(k) = F(k + 1) + F(k - 2), where F(1) is the same as F(2) and
+//   F(0) is nothing
+// Indices only ever grow by a couple, so this works past F(186) too,
+// it's only from_zeckendorf that can't
+pub fn zeckendorf_add(a: &[u128], b: &[u128]) -> Vec<u128> {
+    let highest = a.iter().chain(b).cloned().max().unwrap_or(0) as usize;
+    let mut counts = vec![0u32; highest + 3];
+    for &fib_num in a.iter().chain(b) {
+        match fib_num {
+            0 => {}
+            1 => counts[2] += 1,
+            _ => counts[fib_num as usize] += 1,
+        }
+    }
+
+    let mut carried = true;
+    while carried {
+        carried = false;
+        if counts[counts.len() - 2..].iter().any(|&count| count > 0) {
+            counts.resize(counts.len() + 2, 0);
+        }
+        for k in (2..counts.len() - 2).rev() {
+            if counts[k] >= 2 {
+                counts[k] -= 2;
+                counts[k + 1] += 1;
+                match k {
+                    2 => {}
+                    3 => counts[2] += 1,
+                    _ => counts[k - 2] += 1,
+                }
+                carried = true;
+            } else if counts[k] > 0 && counts[k + 1] > 0 {
+                counts[k] -= 1;
+                counts[k + 1] -= 1;
+                counts[k + 2] += 1;
+                carried = true;
+            }
+        }
+    }
+
+    (2..counts.len())
+        .rev()
+        .filter(|&k| counts[k] > 0)
+        .map(|k| k as u128)
+        .collect()
+}
+
+// How many ways n can be written as a sum of non-consecutive F(2) up to
+// F(k), counted the slow way instead of trusting the greedy split. The
+// most those can add up to is F(k + 1) - 1, which cuts off nearly every
+// branch that couldn't work
+fn count_representations(n: u128, k: u128) -> u64 {
+    if n == 0 {
+        return 1;
+    }
+    if k < 2 || lookup_fib(k + 1) - 1 < n {
+        return 0;
+    }
+    let without = count_representations(n, k - 1);
+    let with = if lookup_fib(k) <= n {
+        count_representations(n - lookup_fib(k), k - 2)
+    } else {
+        0
+    };
+    without + with
+}
+
+// Zeckendorf's theorem checked for every n from 1 to max_n: the greedy
+// split really does add up to n, only uses Fibonacci numbers, never two
+// next to each other, and searching every possible split turns up no
+// other way to do it
+pub fn verify_zeckendorf_uniqueness(max_n: u128) -> bool {
+    (1..=max_n).all(|n| {
+        let indices = zeckendorf(n);
+        from_zeckendorf(&indices) == n
+            && indices
+                .iter()
+                .all(|&fib_num| is_fibonacci(lookup_fib(fib_num)))
+            && indices.windows(2).all(|pair| pair[0] - pair[1] >= 2)
+            && count_representations(n, indices[0]) == 1
+            && count_representations(n, MAX_FIB_INDEX_U128 - 1) == 1
+    })
+}
@@ -0,0 +1,20 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_algorithm_quiet() {
+    assert_eq!(run(&["--algorithm", "lookup", "--quiet", "20"]), "6765\n");
+    assert_eq!(
+        run(&["--quiet", "--algorithm", "tailrec", "186"]),
+        "332825110087067562321196029789634457848\n"
+    );
+    assert_eq!(run(&["--quiet", "30"]), "832040\n");
+    assert!(run(&["--algorithm", "bogus", "--quiet", "20"]).starts_with("Usage:"));
+}
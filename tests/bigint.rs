@@ -0,0 +1,87 @@
+use fibonacci_race::better_dynamic_fib;
+use fibonacci_race::bigint::bigint_fib;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+const F_100: &str = "354224848179261915075";
+
+// The plain u128 loop up through F(93), then BigUint takes over, so
+// this only agrees if the handoff from one to the other is right
+fn u128_then_biguint(fib_num: u128) -> BigUint {
+    let (mut a, mut b) = (
+        BigUint::from(better_dynamic_fib(92)),
+        BigUint::from(better_dynamic_fib(93)),
+    );
+    for _ in 93..fib_num {
+        let next = &a + &b;
+        a = b;
+        b = next;
+    }
+    b
+}
+
+type Matrix = [[BigUint; 2]; 2];
+
+fn multiply(x: &Matrix, y: &Matrix) -> Matrix {
+    let cell = |i: usize, j: usize| &x[i][0] * &y[0][j] + &x[i][1] * &y[1][j];
+    [[cell(0, 0), cell(0, 1)], [cell(1, 0), cell(1, 1)]]
+}
+
+// [[1, 1], [1, 0]]^n is [[F(n + 1), F(n)], [F(n), F(n - 1)]], squared up
+// one bit of n at a time
+fn matrix_fib(mut fib_num: u128) -> BigUint {
+    let one = BigUint::one;
+    let zero = BigUint::zero;
+    let mut result: Matrix = [[one(), zero()], [zero(), one()]];
+    let mut base: Matrix = [[one(), one()], [one(), zero()]];
+    while fib_num > 0 {
+        if fib_num & 1 == 1 {
+            result = multiply(&result, &base);
+        }
+        base = multiply(&base, &base);
+        fib_num >>= 1;
+    }
+    result[0][1].clone()
+}
+
+// F(2k) = F(k) (2 F(k + 1) - F(k)) and F(2k + 1) = F(k)^2 + F(k + 1)^2,
+// returning (F(n), F(n + 1))
+fn fast_doubling_fib(fib_num: u128) -> (BigUint, BigUint) {
+    if fib_num == 0 {
+        return (BigUint::zero(), BigUint::one());
+    }
+    let (a, b) = fast_doubling_fib(fib_num / 2);
+    let even = &a * (&b * 2u8 - &a);
+    let odd = &a * &a + &b * &b;
+    if fib_num.is_multiple_of(2) {
+        (even, odd)
+    } else {
+        let next = &even + &odd;
+        (odd, next)
+    }
+}
+
+#[test]
+fn test_bigint_cross_check() {
+    // gcd(F(m), F(n)) = F(gcd(m, n)), so F(100) falls out of two much
+    // bigger ones without ever being computed directly
+    let by_gcd = bigint_fib(300).gcd(&bigint_fib(700));
+
+    let methods = [
+        ("bigint_fib", bigint_fib(100)),
+        ("u128 then BigUint", u128_then_biguint(100)),
+        ("matrix", matrix_fib(100)),
+        ("fast doubling", fast_doubling_fib(100).0),
+        ("gcd", by_gcd),
+    ];
+    for (method, answer) in &methods {
+        assert_eq!(answer.to_string(), F_100, "{} got F(100) wrong", method);
+    }
+
+    for fib_num in 0..=300 {
+        let expected = bigint_fib(fib_num);
+        assert_eq!(matrix_fib(fib_num), expected);
+        assert_eq!(fast_doubling_fib(fib_num).0, expected);
+    }
+}
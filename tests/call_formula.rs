@@ -0,0 +1,42 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// The number right before " calls" on the first line mentioning what
+fn calls_in(stdout: &str, what: &str) -> u64 {
+    let line = stdout.lines().find(|line| line.contains(what)).unwrap();
+    let before = &line[..line.rfind(" calls").unwrap()];
+    before.split_whitespace().last().unwrap().parse().unwrap()
+}
+
+#[test]
+fn test_call_formula_matches_call_count() {
+    for n in &["10", "20"] {
+        let formula = run(&["--call-formula", n]);
+        let measured = run(&["--call-count", n]);
+        assert_eq!(
+            calls_in(&formula, "backtrace_fib("),
+            calls_in(&measured, "simple backtracing/recursion")
+        );
+        assert_eq!(
+            calls_in(&formula, "backtrace_memo_fib("),
+            calls_in(&measured, "backtracing/recursion with memoization")
+        );
+    }
+    assert!(run(&["--call-formula", "20"]).contains("2 F(21) - 1 = 21891 calls"));
+}
+
+#[test]
+fn test_call_formula_limit() {
+    // 2 F(185) - 1 is too big for a u128, so 183 is the last one
+    assert!(run(&["--call-formula", "183"]).starts_with("backtrace_fib(183) makes"));
+    for n in &["184", "185"] {
+        assert!(run(&["--call-formula", n]).starts_with("Usage:"));
+    }
+}
@@ -0,0 +1,77 @@
+use fibonacci_race::bigint::{bigint_fib, bigint_fib_checkpointed, read_checkpoint};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+// Somewhere no other test (or a second run of this one) is using
+fn checkpoint_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "fibonacci_race-{}-{}.checkpoint",
+        name,
+        std::process::id()
+    ));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn test_checkpoint_resume() {
+    let path = checkpoint_path("resume");
+
+    // stopping at 500 leaves the checkpoint there, like getting
+    // interrupted right after saving it would
+    assert_eq!(
+        bigint_fib_checkpointed(500, &path, 100).unwrap(),
+        bigint_fib(500)
+    );
+    let checkpoint = read_checkpoint(&path).unwrap().unwrap();
+    assert_eq!(checkpoint.i, 500);
+    assert_eq!(checkpoint.a, bigint_fib(500));
+    assert_eq!(checkpoint.b, bigint_fib(501));
+
+    assert_eq!(
+        bigint_fib_checkpointed(1000, &path, 100).unwrap(),
+        bigint_fib(1000)
+    );
+    assert_eq!(read_checkpoint(&path).unwrap().unwrap().i, 1000);
+
+    // already past 10, so it's left alone
+    assert_eq!(
+        bigint_fib_checkpointed(10, &path, 100).unwrap(),
+        bigint_fib(10)
+    );
+    assert_eq!(read_checkpoint(&path).unwrap().unwrap().i, 1000);
+
+    fs::write(&path, "not a checkpoint").unwrap();
+    assert!(bigint_fib_checkpointed(1000, &path, 100).is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_checkpoint_cli() {
+    let path = checkpoint_path("cli");
+    let run = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+            .arg("--checkpoint")
+            .arg(&path)
+            .args(args)
+            .output()
+            .unwrap();
+        (
+            String::from_utf8(output.stdout).unwrap(),
+            String::from_utf8(output.stderr).unwrap(),
+        )
+    };
+
+    let (stdout, _) = run(&["--checkpoint-every", "100", "500"]);
+    assert_eq!(stdout.trim(), bigint_fib(500).to_string());
+    let (stdout, stderr) = run(&["1000"]);
+    assert_eq!(stdout.trim(), bigint_fib(1000).to_string());
+    assert!(stderr.starts_with("Picking up from F(500)"));
+
+    assert!(run(&["--checkpoint-every", "0", "500"])
+        .0
+        .starts_with("Usage:"));
+    assert!(run(&[]).0.starts_with("Usage:"));
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,20 @@
+use std::process::Command;
+
+fn checksum(hex: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--checksum", hex])
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_checksum() {
+    assert_eq!(
+        checksum("010203"),
+        "Fibonacci checksum of 3 bytes: 00000000000000000000000000000005\n"
+    );
+    assert_ne!(checksum("deadbeef"), checksum("deadbeee"));
+    assert!(checksum("abc").starts_with("Usage:"));
+    assert!(checksum("zz").starts_with("Usage:"));
+}
@@ -0,0 +1,26 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_cold_start() {
+    let stdout = run(&["--cold-start", "--cache-size-kb", "64", "15"]);
+    assert_eq!(stdout.matches("Solving fib:15 with").count(), 24);
+    assert_eq!(
+        run(&["--cold-start", "15"])
+            .matches("Solving fib:15 with")
+            .count(),
+        24
+    );
+
+    assert!(run(&["--cold-start", "--cache-size-kb", "0", "15"]).starts_with("Usage:"));
+    assert!(run(&["--cold-start", "--cache-size-kb", "big", "15"]).starts_with("Usage:"));
+    // the size doesn't mean anything without --cold-start
+    assert!(run(&["--cache-size-kb", "64", "15"]).starts_with("Usage:"));
+}
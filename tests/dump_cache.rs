@@ -0,0 +1,28 @@
+use std::process::Command;
+
+#[test]
+fn test_dump_cache() {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--dump-cache", "20"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let dump = &stdout[stdout.find("cached_fib has 21 entries").unwrap()..];
+    for line in &["F(0) = 0", "F(1) = 1", "F(19) = 4181", "F(20) = 6765"] {
+        assert!(
+            dump.contains(&format!("  {}\n", line)),
+            "no {} in the dump",
+            line
+        );
+    }
+
+    // --quiet keeps stdout to just the answer
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--dump-cache", "--quiet", "20"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "6765\n");
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("  F(19) = 4181\n"));
+}
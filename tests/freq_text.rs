@@ -0,0 +1,23 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn test_freq_text() {
+    let path = std::env::temp_dir().join(format!("freq_text_{}.txt", std::process::id()));
+    fs::write(
+        &path,
+        "13 apples and 8 pears\n4 plums, 13 figs, 1000 grapes\n",
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .arg("--freq-text")
+        .arg(&path)
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout,
+        "value count\n    8     1\n   13     2\n60.0% of the numbers are Fibonacci numbers\n"
+    );
+}
@@ -0,0 +1,80 @@
+use fibonacci_race::better_dynamic_fib;
+use fibonacci_race::iter::lucas_iter;
+
+// Everything here stays at or below n = 93, where the product of two
+// neighbouring Fibonacci numbers still fits in an i128 to allow for the
+// signs
+const MAX_N: u128 = 93;
+
+fn fib(n: u128) -> i128 {
+    better_dynamic_fib(n) as i128
+}
+
+fn sign(n: u128) -> i128 {
+    if n.is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+// Cassini's identity, F(n - 1)F(n + 1) - F(n)^2 = (-1)^n (Cassini, 1680;
+// Knuth, The Art of Computer Programming, Vol. 1, section 1.2.8)
+#[test]
+fn test_cassini() {
+    for n in 1..=MAX_N {
+        assert_eq!(
+            fib(n - 1) * fib(n + 1) - fib(n) * fib(n),
+            sign(n),
+            "n = {}",
+            n
+        );
+    }
+}
+
+// d'Ocagne's identity, F(m)F(n + 1) - F(m + 1)F(n) = (-1)^n F(m - n) for
+// m >= n (d'Ocagne; Vajda, Fibonacci & Lucas Numbers, and the Golden
+// Section, 1989). F(93) × F(94) would be too big, so m stops at 92
+#[test]
+fn test_d_ocagne() {
+    for m in 0..MAX_N {
+        for n in 0..=m {
+            assert_eq!(
+                fib(m) * fib(n + 1) - fib(m + 1) * fib(n),
+                sign(n) * fib(m - n),
+                "m = {}, n = {}",
+                m,
+                n
+            );
+        }
+    }
+}
+
+// F(2n) = F(n)L(n), the Lucas numbers being L(0) = 2, L(1) = 1 and then
+// the same adding (Lucas, 1878; Vajda, 1989)
+#[test]
+fn test_fibonacci_lucas() {
+    for (n, lucas) in lucas_iter().take(MAX_N as usize + 1).enumerate() {
+        let n = n as u128;
+        assert_eq!(
+            better_dynamic_fib(2 * n),
+            better_dynamic_fib(n) * lucas,
+            "n = {}",
+            n
+        );
+    }
+}
+
+// The shallow diagonals of Pascal's triangle add up to the Fibonacci
+// numbers: the sum of C(n - k, k) over k from 0 to n / 2 is F(n + 1)
+// (Lucas, 1876; Knuth, The Art of Computer Programming, Vol. 1, section
+// 1.2.8)
+#[test]
+fn test_binomial_diagonals() {
+    // C(n, k) one row at a time, each step staying a whole number
+    let binomial = |n: u128, k: u128| (0..k).fold(1u128, |c, i| c * (n - i) / (i + 1));
+    for n in 0..=MAX_N {
+        let sum: u128 = (0..=n / 2).map(|k| binomial(n - k, k)).sum();
+        assert_eq!(sum, better_dynamic_fib(n + 1), "n = {}", n);
+    }
+}
@@ -0,0 +1,41 @@
+use fibonacci_race::report::BenchmarkReport;
+use std::process::Command;
+use std::time::Duration;
+
+#[test]
+fn test_latex_output() {
+    let mut report = BenchmarkReport::default();
+    report.record("cached_fib & friends", 20, Duration::from_nanos(1234));
+    let tabular = report.to_latex_tabular();
+    assert!(tabular.starts_with("\\begin{tabular}"));
+    assert!(tabular.ends_with("\\end{tabular}"));
+    assert!(tabular.contains("cached\\_fib \\& friends & 20 & 1234 \\\\"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--latex", "20"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // pdflatex won't take a \caption outside of a float, so the tabular
+    // comes wrapped in a table with the caption and label after it, and
+    // that's all there is on stdout
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines[..3],
+        [
+            "\\begin{table}[ht]",
+            "\\centering",
+            "\\begin{tabular}{|l|r|r|}"
+        ]
+    );
+    assert_eq!(
+        lines[lines.len() - 4..],
+        [
+            "\\end{tabular}",
+            "\\caption{Fibonacci race timings over 3 rounds}",
+            "\\label{fig:fib-race}",
+            "\\end{table}"
+        ]
+    );
+    assert_eq!(stdout.matches("simple backtracing/recursion").count(), 3);
+}
@@ -0,0 +1,45 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> (String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn test_n_sequence() {
+    let (stdout, _) = run(&["--n-sequence", "5,10,20"]);
+    let sections: Vec<&str> = stdout.split("=== n=").skip(1).collect();
+    assert_eq!(sections.len(), 3);
+    for (section, n) in sections.iter().zip(&["5", "10", "20"]) {
+        assert!(section.starts_with(&format!("{} ===", n)));
+        assert!(section.contains(&format!("By the way, Fibonacci Number {} is", n)));
+    }
+}
+
+#[test]
+fn test_n_sequence_skips_bad_values() {
+    let (stdout, _) = run(&["--n-sequence", "5,abc,1,10"]);
+    assert_eq!(stdout.matches("=== n=").count(), 4);
+    assert!(stdout.contains("abc isn't a Fibonacci Number index"));
+    assert!(stdout.contains("Fibonacci Number 1 is really boring"));
+    assert_eq!(stdout.matches("By the way").count(), 2);
+}
+
+#[test]
+fn test_n_sequence_json() {
+    let (stdout, stderr) = run(&["--json", "--n-sequence", "5,nope,10,20"]);
+    assert!(stdout.starts_with("[\n[\n"));
+    assert!(stdout.trim_end().ends_with("]\n]"));
+    assert_eq!(stdout.matches("\n]").count(), 4);
+    for n in &[5, 10, 20] {
+        // 8 algorithms, 3 rounds each
+        assert_eq!(stdout.matches(&format!("\"n\": {},", n)).count(), 24);
+    }
+    assert!(stderr.contains("n=nope: nope isn't a Fibonacci Number index"));
+}
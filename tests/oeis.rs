@@ -0,0 +1,19 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_oeis() {
+    assert_eq!(
+        run(&["--oeis"]),
+        "A000045 Fibonacci numbers\nhttps://oeis.org/A000045\n0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610, 987, 1597, 2584, 4181\n"
+    );
+    assert!(run(&["--oeis-lucas"]).starts_with("A000032 Lucas numbers\n"));
+    assert!(run(&["--oeis-tribonacci"]).ends_with("\n0, 0, 1, 1, 2, 4, 7, 13, 24, 44, 81, 149, 274, 504, 927, 1705, 3136, 5768, 10609, 19513\n"));
+}
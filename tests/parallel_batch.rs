@@ -0,0 +1,30 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn test_parallel_batch() {
+    let path = std::env::temp_dir().join(format!("parallel_batch_{}.txt", std::process::id()));
+    let fib_nums = [
+        90, 3, 17, 186, 0, 42, 8, 150, 1, 64, 33, 99, 5, 120, 12, 76, 20, 2, 55, 170,
+    ];
+    let contents: Vec<String> = fib_nums.iter().map(u128::to_string).collect();
+    fs::write(&path, contents.join("\n") + "\nbogus\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--parallel-batch", path.to_str().unwrap()])
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    let solved: Vec<u128> = stdout
+        .lines()
+        .map(|line| line[2..line.find(')').unwrap()].parse().unwrap())
+        .collect();
+    let mut sorted = fib_nums.to_vec();
+    sorted.sort_unstable();
+    assert_eq!(solved, sorted);
+    assert!(stdout.contains("F(20) = 6765 ["));
+    assert!(stderr.contains(":21: bogus isn't a Fibonacci Number index"));
+}
@@ -0,0 +1,50 @@
+use fibonacci_race::lookup_fib;
+use fibonacci_race::plugin::FibPlugin;
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::path::PathBuf;
+use std::process::Command;
+
+// examples/plugin_example, built the same way the README says to. Only
+// cargo test on its own builds examples, cargo test --test plugin_test
+// doesn't, so it gets built here either way (which is quick when it's
+// already up to date)
+fn plugin_path() -> PathBuf {
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--example", "plugin_example"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    // the binary is in target/debug, and examples go in a folder next to it
+    PathBuf::from(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .parent()
+        .unwrap()
+        .join("examples")
+        .join(format!("{}plugin_example{}", DLL_PREFIX, DLL_SUFFIX))
+}
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_plugin() {
+    let path = plugin_path();
+    let plugin = FibPlugin::load(path.to_str().unwrap()).unwrap();
+    assert_eq!(plugin.name(), "better_dynamic_fib, loaded as a plugin");
+    for fib_num in 0..=186 {
+        assert_eq!(plugin.compute(fib_num), lookup_fib(fib_num));
+    }
+
+    // raced once a round, after the 8 built in algorithms
+    let stdout = run(&["--plugin", path.to_str().unwrap(), "20"]);
+    assert_eq!(stdout.matches("Solving fib:20 with").count(), 27);
+    assert_eq!(stdout.matches("loaded as a plugin").count(), 3);
+    assert!(!stdout.contains("but it's"));
+
+    assert!(FibPlugin::load("no/such/plugin.so").is_err());
+    assert!(run(&["--plugin", "no/such/plugin.so", "20"]).starts_with("Couldn't load plugin"));
+}
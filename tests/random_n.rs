@@ -0,0 +1,26 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+
+// Only the first line matters, and a big n could keep the simple
+// recursion busy for ages, so don't wait around for the race
+fn picked_n(args: &[&str]) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(args)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut first_line = String::new();
+    BufReader::new(child.stdout.take().unwrap())
+        .read_line(&mut first_line)
+        .unwrap();
+    child.kill().unwrap();
+    child.wait().unwrap();
+    first_line
+}
+
+#[test]
+fn test_seeded_random_n() {
+    let first = picked_n(&["--seed", "42", "--random-n"]);
+    assert!(first.starts_with("Randomly picked n = "));
+    assert_eq!(first, picked_n(&["--random-n", "--seed", "42"]));
+}
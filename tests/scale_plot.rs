@@ -0,0 +1,15 @@
+use std::process::Command;
+
+#[test]
+fn test_scale_plot() {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--scale-plot", "20"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let bars: Vec<&str> = stdout.lines().filter(|line| line.contains('×')).collect();
+    // every algorithm, every round
+    assert_eq!(bars.len(), 24);
+    assert!(bars.iter().all(|line| line.contains('█')));
+    assert!(bars.iter().any(|line| line.ends_with(" 1.0×")));
+}
@@ -0,0 +1,21 @@
+use std::process::Command;
+
+#[test]
+fn test_sparkline() {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--rounds", "8", "--sparkline", "15"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("Solving fib:15 with").count(), 64);
+    let sparks = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let lines: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.contains(&sparks[..]))
+        .collect();
+    // one line for each algorithm, with a spark for each round
+    assert_eq!(lines.len(), 8);
+    for line in lines {
+        assert_eq!(line.matches(&sparks[..]).count(), 8, "{}", line);
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(unix)]
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_stream_until_sigterm() {
+    // the simple recursion bogs down before long, so there is
+    // still something left to interrupt after 20 lines
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--stream", "backtrace"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+
+    let mut expected = (0u128, 1u128);
+    for fib_num in 0..20 {
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+        let prefix = format!("F({}) = {} [", fib_num, expected.0);
+        assert!(line.starts_with(&prefix), "unexpected line {:?}", line);
+        assert!(line.ends_with(" ns]\n"), "unexpected line {:?}", line);
+        expected = (expected.1, expected.0 + expected.1);
+    }
+
+    Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()
+        .unwrap();
+    let mut rest = String::new();
+    stdout.read_to_string(&mut rest).unwrap();
+    assert!(child.wait().unwrap().success());
+    assert!(rest.contains("Highest Fibonacci Number reached: F("));
+    assert!(rest.contains("Total time: "));
+}
@@ -0,0 +1,12 @@
+use std::process::Command;
+
+#[test]
+fn test_validate_sequence() {
+    let output = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--validate-sequence", "20"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("algorithms agree F(20) = F(19) + F(18)"));
+    assert!(!stdout.contains("failed validation"));
+}
@@ -0,0 +1,44 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_watch_reruns_on_enter() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--watch", "15"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // two Enters and then EOF makes three rounds
+    child.stdin.take().unwrap().write_all(b"\n\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let rounds: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("=== round "))
+        .collect();
+    assert_eq!(
+        rounds,
+        ["=== round 1 ===", "=== round 2 ===", "=== round 3 ==="]
+    );
+    assert_eq!(stdout.matches("Solving fib:15 with").count(), 24);
+}
+
+#[test]
+fn test_watch_interval() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fibonacci_race"))
+        .args(["--watch-interval", "0.01", "15"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // it never stops by itself, so just wait for the third round
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    let third = stdout
+        .lines()
+        .map(Result::unwrap)
+        .find(|line| line.starts_with("=== round 3"));
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert_eq!(third.as_deref(), Some("=== round 3 ==="));
+}